@@ -0,0 +1,30 @@
+#!/bin/bash
+# JIS X 0208の全文字を、Unicode→EUC-JPの対応表(src/jis0208.rsのTABLE)として出力する
+# conveuc.shと同じく、iconvのEUCJPを基準とする。
+#   ./genjis.sh > table.txt
+
+# Windows等で入力される記号(CP932の対応)を、同じ字形の文字に割り当てる
+aliases() {
+    echo "FF5E A1C1" # ～ → 〜
+    echo "2225 A1C2" # ∥ → ‖
+    echo "FF0D A1DD" # － → −
+    echo "FFE0 A1F1" # ￠ → ¢
+    echo "FFE1 A1F2" # ￡ → £
+    echo "FFE2 A2CC" # ￢ → ¬
+}
+
+{
+    for hi in $(seq 161 244); do
+        for lo in $(seq 161 254); do
+            printf -v euc '%02X%02X' "$hi" "$lo"
+            u=$(printf "\\x${euc:0:2}\\x${euc:2:2}" |
+                iconv -f EUCJP -t UTF-16BE 2>/dev/null | od -An -tx1 | tr -d ' \n')
+            [ ${#u} -eq 4 ] && echo "${u^^} $euc"
+        done
+    done
+    aliases
+} | LC_ALL=C sort | awk '
+    { line = line sprintf("(0x%s, 0x%s), ", $1, $2) }
+    NR % 6 == 0 { print "    " substr(line, 1, length(line) - 1); line = "" }
+    END { if (line != "") print "    " substr(line, 1, length(line) - 1) }
+'
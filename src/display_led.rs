@@ -1,81 +1,1571 @@
 //! matrix_ledをキャラクタディスプレイとして使用する
 //! matrix_ledモジュールは、display_ledを経由して使用する。
+//! LedPanelを実装したものであれば、MAX7219以外のパネルでも使用できる。
+//!
+//! パネルに収まらない長さの行は、マーキー(set_marquee)を設定すると、
+//! tick()毎に1ドットずつ左へ流して表示する。
+//! パネルに収まる行は、set_align()で左・中央・右に揃える。
+//! 収まらない行の扱い(流す・切り捨てる・縮めたフォントにする)は、set_overflow()で選ぶ。
+//! 文字列の幅は、measure()で求められる。
+//!
+//! 行の切り替えは、トランジション(set_transition)を設定すると、
+//! 前の行が上(下)へ抜け、新しい行が入ってくるように1ドットずつ流す。
+//! 変化した文字のみを流すこともできる。(時計の桁送り等)
+//!
+//! 文字列はUTF-8で、既定では、ASCII・半角カナは美咲フォントの4*8ドット(半角)、
+//! それ以外(かな・漢字・全角記号等、JIS X 0208の文字)は8*8ドット(全角)で表示する。
+//!
+//! フォントは、add_font()で最大MAX_FONTS個まで登録し、set_font()で既定のフォントを選ぶ。
+//!
+//! 文字列中の制御文字・エスケープシーケンス(VT100の一部)を解釈する。
+//! 桁は文字単位で、1から数える。属性(SGR)は、改行後も続く。
+//!   \r           : 行頭へ
+//!   \x08         : 1文字戻る
+//!   ESC [ n G    : n桁目へ
+//!   ESC [ m;n H  : n桁目へ(行mは無視)
+//!   ESC [ 2 J    : 画面を消去し、行頭へ
+//!   ESC [ n K    : 行の消去(0:カーソルから行末 1:行頭からカーソル 2:行全体)
+//!   ESC [ n m    : 属性(SGR)
+//!                    0:リセット 5:点滅 25:点滅解除 7:反転 27:反転解除
+//!                    10:既定のフォント 11〜19:1〜9番のフォント
+//!   ESC [ n q    : 輝度(0〜MAX_BRIGHTNESS)
+//! 解釈できないシーケンスは無視する。
+//!
+//! 文字の配置(set_layout)をプロポーショナルにすると、各文字の点灯ドットのある幅だけを
+//! 詰めて並べる。文字幅の表・文字間の空白・カーニングも指定できる。
 
-use super::matrix_led::Matrix;
+use super::font::{Font, MAX_GLYPH_WIDTH, MISAKI};
+#[cfg(target_os = "none")]
+use super::matrix_led::PanelConfig;
+use super::matrix_led::{Error, Matrix, Result};
+use super::panel::LedPanel;
+#[cfg(target_os = "none")]
+use super::spi_dma::{SpiDma, SpiDmaConfig};
+use super::transport::Transport;
+use super::video_ram::VideoRam;
 //use cortex_m;
 //use cortex_m_semihosting::dbg;
+#[cfg(target_os = "none")]
 use stm32f4::stm32f401;
 
+/// 1行の最大文字数
+const LINE_SIZE: usize = 50;
+
+/// 半角文字の幅(ドット)
+const GLYPH_WIDTH: usize = 4;
+
+/// 登録できるフォントの数(0番は既定の美咲フォント)
+pub const MAX_FONTS: usize = 10;
+
+/// エスケープシーケンスのパラメータの最大数
+const MAX_PARAMS: usize = 4;
+
+/// エスケープシーケンス
+const ESC: char = '\x1b';
+
+/// 点滅の既定の半周期(tick数)
+const DEFAULT_BLINK_TICKS: u32 = 50;
+
+/// 文字の属性
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Style {
+    font: Option<u8>, // フォントの番号(Noneは既定のフォント)
+    inverse: bool,
+    blink: bool,
+}
+
+impl Style {
+    const DEFAULT: Style = Style {
+        font: None,
+        inverse: false,
+        blink: false,
+    };
+}
+
+/// 行の1文字
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Cell {
+    c: char,
+    style: Style,
+}
+
+impl Cell {
+    const BLANK: Cell = Cell {
+        c: ' ',
+        style: Style::DEFAULT,
+    };
+}
+
+/// エスケープシーケンスの解釈の状態
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Escape {
+    /// 通常の文字
+    Ground,
+    /// ESCの次
+    Esc,
+    /// ESC [ の後、パラメータの途中
+    Csi {
+        params: [u16; MAX_PARAMS],
+        count: usize,
+    },
+}
+
+/// 登録したフォント
+#[derive(Clone, Copy)]
+struct Fonts {
+    table: [Option<&'static dyn Font>; MAX_FONTS],
+    default: usize,
+}
+
+impl Fonts {
+    /// 番号fontのフォント(Noneや登録がなければ、番号defaultのフォント)
+    fn get(&self, font: Option<u8>, default: usize) -> &'static dyn Font {
+        font.and_then(|n| self.table[n as usize])
+            .or(self.table[default])
+            .or(self.table[self.default])
+            .unwrap_or(&MISAKI)
+    }
+}
+
+/// マーキー(流れる文字)の設定
+///
+/// 時間は、全てtick数で指定する。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Marquee {
+    pub ticks_per_step: u32, // 1ドット進める間隔
+    pub loops: Option<u32>,  // 繰り返し回数(Noneは無限、0は流さない)
+    pub gap: u32,            // 繰り返しの間の空白(ドット) 往復では使わない
+    pub pause_start: u32,    // 先頭を表示した状態で止まる時間
+    pub pause_end: u32,      // 末尾がパネルの右端に来た状態で止まる時間
+    pub ping_pong: bool,     // 往復するか
+}
+
+impl Marquee {
+    /// ticks_per_step毎に1ドット進め、無限に繰り返す(空白・停止無し)
+    pub const fn new(ticks_per_step: u32) -> Marquee {
+        Marquee {
+            ticks_per_step,
+            loops: None,
+            gap: 0,
+            pause_start: 0,
+            pause_end: 0,
+            ping_pong: false,
+        }
+    }
+
+    /// 繰り返し回数を指定する。終わると先頭を表示して止まる
+    ///
+    /// 0の場合は流さず、収まらない行も先頭を表示したままとする。
+    pub const fn with_loops(self, loops: u32) -> Marquee {
+        Marquee {
+            loops: Some(loops),
+            ..self
+        }
+    }
+
+    /// 繰り返しの間の空白を指定する
+    pub const fn with_gap(self, gap: u32) -> Marquee {
+        Marquee { gap, ..self }
+    }
+
+    /// 先頭・末尾で止まる時間を指定する
+    pub const fn with_pause(self, pause_start: u32, pause_end: u32) -> Marquee {
+        Marquee {
+            pause_start,
+            pause_end,
+            ..self
+        }
+    }
+
+    /// 往復させる
+    pub const fn with_ping_pong(self) -> Marquee {
+        Marquee {
+            ping_pong: true,
+            ..self
+        }
+    }
+}
+
+impl Default for Marquee {
+    /// 5tick毎に1ドット、2文字分の空白、先頭で100tick停止
+    fn default() -> Self {
+        Self::new(5)
+            .with_gap(2 * GLYPH_WIDTH as u32)
+            .with_pause(100, 0)
+    }
+}
+
+/// 文字の並べ方
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Pitch {
+    /// 等幅(半角4ドット・全角8ドット)
+    Fixed,
+    /// プロポーショナル(文字毎の幅)
+    Proportional,
+}
+
+/// 文字の幅の指定(プロポーショナル用)
+///
+/// leftは、フォントのビットマップの左端から、文字の左端までのドット数
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GlyphWidth {
+    pub c: char,
+    pub left: u8,
+    pub width: u8,
+}
+
+/// カーニング(文字の組の間隔の調整、プロポーショナル用)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Kerning {
+    pub left: char,
+    pub right: char,
+    pub adjust: i8, // 右の文字をずらすドット数(負で詰める)
+}
+
+/// 文字の配置の設定
+///
+/// 文字幅の表にない文字の幅は、ビットマップの空白の列を除いて求める。
+/// 点灯ドットのない文字(空白等)の幅は、space_width(全角はその2倍)とする。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TextLayout {
+    pub pitch: Pitch,
+    pub spacing: u8,     // 文字間の空白(ドット)
+    pub space_width: u8, // 空白文字の幅(ドット)
+    pub widths: &'static [GlyphWidth],
+    pub kerning: &'static [Kerning],
+}
+
+impl TextLayout {
+    /// 等幅
+    pub const fn fixed() -> TextLayout {
+        TextLayout {
+            pitch: Pitch::Fixed,
+            spacing: 0,
+            space_width: 0,
+            widths: &[],
+            kerning: &[],
+        }
+    }
+
+    /// プロポーショナル(文字間1ドット、空白2ドット)
+    pub const fn proportional() -> TextLayout {
+        TextLayout {
+            pitch: Pitch::Proportional,
+            spacing: 1,
+            space_width: 2,
+            widths: &[],
+            kerning: &[],
+        }
+    }
+
+    /// 文字間の空白を指定する
+    pub const fn with_spacing(self, spacing: u8) -> TextLayout {
+        TextLayout { spacing, ..self }
+    }
+
+    /// 空白文字の幅を指定する
+    pub const fn with_space_width(self, space_width: u8) -> TextLayout {
+        TextLayout {
+            space_width,
+            ..self
+        }
+    }
+
+    /// 文字幅の表を指定する
+    pub const fn with_widths(self, widths: &'static [GlyphWidth]) -> TextLayout {
+        TextLayout { widths, ..self }
+    }
+
+    /// カーニングの表を指定する
+    pub const fn with_kerning(self, kerning: &'static [Kerning]) -> TextLayout {
+        TextLayout { kerning, ..self }
+    }
+
+    /// プロポーショナルでの文字cの(左端, 幅)
+    fn metrics(&self, c: char, font: &[u8], cell: usize) -> (i32, i32) {
+        if let Some(w) = self.widths.iter().find(|w| w.c == c) {
+            return (w.left as i32, w.width as i32);
+        }
+        // フォントは、幅cellでLSBより詰めてある
+        let mask = ((1u16 << cell) - 1) as u8;
+        let bits = font.iter().fold(0, |acc, line| acc | line) & mask;
+        if bits == 0 {
+            let space = self.space_width as usize * cell / GLYPH_WIDTH;
+            return (0, space as i32);
+        }
+        let high = 7 - bits.leading_zeros() as i32;
+        let low = bits.trailing_zeros() as i32;
+        (cell as i32 - 1 - high, high - low + 1)
+    }
+
+    /// 文字の組(left, right)のカーニング
+    fn kerning(&self, left: char, right: char) -> i32 {
+        self.kerning
+            .iter()
+            .find(|k| k.left == left && k.right == right)
+            .map_or(0, |k| k.adjust as i32)
+    }
+}
+
+impl Default for TextLayout {
+    fn default() -> Self {
+        TextLayout::fixed()
+    }
+}
+
+/// 行の揃え方
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// パネルに収まらない行の扱い
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Overflow {
+    /// 左揃えで、はみ出した分は表示しない(マーキーを設定していても流さない)
+    Clip,
+    /// マーキーを設定していれば流す(なければClipと同じ)
+    Marquee,
+    /// 番号nのフォントに切り替える(既定のフォントの文字のみ)
+    /// それでも収まらなければ、Marqueeと同じ
+    Condense(usize),
+}
+
+/// 行の切り替え時に流す方向
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Roll {
+    /// 前の行が上へ抜け、新しい行が下から入る
+    Up,
+    /// 前の行が下へ抜け、新しい行が上から入る
+    Down,
+}
+
+/// 行の切り替えのトランジションの設定
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Transition {
+    pub roll: Roll,
+    pub ticks_per_step: u32, // 1ドット流す間隔(tick数)
+    pub changed_only: bool,  // 変化した文字のみを流すか
+}
+
+impl Transition {
+    /// 行全体を、ticks_per_step毎に1ドット流す
+    pub const fn new(roll: Roll, ticks_per_step: u32) -> Transition {
+        Transition {
+            roll,
+            ticks_per_step,
+            changed_only: false,
+        }
+    }
+
+    /// 変化した文字のみを流す
+    pub const fn with_changed_only(self) -> Transition {
+        Transition {
+            changed_only: true,
+            ..self
+        }
+    }
+}
+
+/// トランジションの進行状態
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Rolling {
+    step: usize, // 流したドット数(行の高さで完了)
+    wait: u32,   // 次に進めるまでのtick数
+}
+
+/// マーキーの進行状態
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Scroll {
+    offset: i32,   // 行の先頭の、パネル左端からのずれ(左へ正)
+    forward: bool, // true:左へ流れる false:右へ戻る(往復時)
+    wait: u32,     // 次に進めるまでのtick数
+    loops: u32,    // 終えた繰り返し回数
+}
+
 ///ディスプレイドライバ
-pub struct DisplayLed<'a> {
-    led: Matrix<'a>,
-    buff: [u8; 50],
+pub struct DisplayLed<P: LedPanel> {
+    led: P,
+    buff: [Cell; LINE_SIZE],
     buff_len: usize,
+    line: [Cell; LINE_SIZE], // 表示中の行
+    line_len: usize,
+    marquee: Option<Marquee>,
+    scroll: Option<Scroll>,  // マーキーの進行中のみSome
+    prev: [Cell; LINE_SIZE], // 切り替え前の行
+    prev_len: usize,
+    transition: Option<Transition>,
+    rolling: Option<Rolling>, // トランジションの進行中のみSome
+    layout: TextLayout,
+    fonts: Fonts,
+    style: Style,  // 書き込み中の文字の属性
+    cursor: usize, // 書き込み位置(buff上の文字数)
+    escape: Escape,
+    unsent: bool,     // 送信に失敗し、表示できていない内容があるか
+    blink_ticks: u32, // 点滅の半周期(tick数)
+    blink_count: u32,
+    blink_on: bool, // 点滅中の文字を表示する期間か
+    align: Align,
+    overflow: Overflow,
+    line_font: usize, // 表示中の行の既定のフォント
 }
 
-impl<'a> DisplayLed<'a> {
+#[cfg(target_os = "none")]
+impl<'a> DisplayLed<Matrix<SpiDma<'a>>> {
+    /// SPI1+DMA2経由のMatrix LED(32*8)で初期化する
     pub fn new(device: &'a stm32f401::Peripherals) -> Self {
+        Self::with_config(device, PanelConfig::default())
+    }
+
+    /// SPI1+DMA2経由のMatrix LEDを、指定のパネル構成で初期化する
+    pub fn with_config(device: &'a stm32f401::Peripherals, config: PanelConfig) -> Self {
+        Self::from_matrix(Matrix::with_config(SpiDma::new(device), config))
+    }
+
+    /// 指定の送信路構成(SPI・CS端子・DMAチャンネル)とパネル構成で初期化する
+    ///
+    /// DMAチャンネルを変えれば、2本のチェーンをそれぞれ別のDisplayLedとして使える。
+    /// CSの端子がポートにない場合は、Error::OutOfRange
+    pub fn with_spi_config(
+        device: &'a stm32f401::Peripherals,
+        spi_config: SpiDmaConfig,
+        config: PanelConfig,
+    ) -> Result<Self> {
+        let transport = SpiDma::with_config(device, spi_config)?;
+        Ok(Self::from_matrix(Matrix::with_config(transport, config)))
+    }
+}
+
+impl<T: Transport> DisplayLed<Matrix<T>> {
+    /// 任意の送信路で初期化する
+    pub fn with_transport(transport: T) -> Self {
+        Self::from_matrix(Matrix::new(transport))
+    }
+
+    /// 初期化済みのMatrixから作る
+    pub fn from_matrix(led: Matrix<T>) -> Self {
+        Self::from_panel(led)
+    }
+}
+
+impl<P: LedPanel> DisplayLed<P> {
+    /// 初期化済みのパネルから作る
+    pub fn from_panel(led: P) -> Self {
         let mut display = DisplayLed {
-            led: Matrix::new(&device),
-            buff: [0; 50],
+            led,
+            buff: [Cell::BLANK; LINE_SIZE],
             buff_len: 0,
+            line: [Cell::BLANK; LINE_SIZE],
+            line_len: 0,
+            marquee: None,
+            scroll: None,
+            prev: [Cell::BLANK; LINE_SIZE],
+            prev_len: 0,
+            transition: None,
+            rolling: None,
+            layout: TextLayout::fixed(),
+            fonts: Fonts {
+                table: [None; MAX_FONTS],
+                default: 0,
+            },
+            style: Style::DEFAULT,
+            cursor: 0,
+            escape: Escape::Ground,
+            unsent: false,
+            blink_ticks: DEFAULT_BLINK_TICKS,
+            blink_count: 0,
+            blink_on: true,
+            align: Align::Left,
+            overflow: Overflow::Marquee,
+            line_font: 0,
         };
-        display.led.clear();
+        display.fonts.table[0] = Some(&MISAKI);
+        display.led.video_ram_mut().clear();
         display
     }
 
+    /// Matrix LEDへの参照
+    pub fn matrix(&self) -> &P {
+        &self.led
+    }
+
+    /// Matrix LEDへの可変参照
+    /// 輝度設定等は、こちらから直接行う。
+    pub fn matrix_mut(&mut self) -> &mut P {
+        &mut self.led
+    }
+
+    /// 時間経過の通知(フェード・マーキー等の進行)
+    pub fn tick(&mut self) {
+        // 転送中で送れなかった分は、次のtickで送られる
+        let _ = self.led.tick();
+        // トランジションの間は、マーキーを進めない
+        let changed = if self.rolling.is_some() {
+            self.step_rolling()
+        } else {
+            self.step_scroll()
+        };
+        let blinked = self.step_blink();
+        // 送信に失敗した行は、ここで送り直す
+        if changed || blinked || self.unsent {
+            let _ = self.show_line();
+        }
+    }
+
     pub fn clear(&mut self) {
-        self.led.clear();
+        self.led.video_ram_mut().clear();
+    }
+
+    /// マーキーを設定する(Noneで解除)
+    ///
+    /// 表示中の行は、先頭から流し直す。
+    pub fn set_marquee(&mut self, marquee: Option<Marquee>) -> Result<()> {
+        self.marquee = marquee;
+        self.start_scroll();
+        self.show_line()
+    }
+
+    /// マーキーの設定
+    pub fn marquee(&self) -> Option<Marquee> {
+        self.marquee
+    }
+
+    /// マーキーで流している最中か
+    pub fn is_scrolling(&self) -> bool {
+        self.scroll.is_some()
+    }
+
+    /// 文字の配置を設定する
+    ///
+    /// 表示中の行は、新しい配置で描き直す。(マーキーは先頭から流し直す)
+    pub fn set_layout(&mut self, layout: TextLayout) -> Result<()> {
+        self.layout = layout;
+        self.start_scroll();
+        self.show_line()
+    }
+
+    /// 文字の配置の設定
+    pub fn layout(&self) -> TextLayout {
+        self.layout
+    }
+
+    /// フォントを登録し、その番号を返す
+    ///
+    /// 空きがなければ、Error::BufferOverflow
+    pub fn add_font(&mut self, font: &'static dyn Font) -> Result<usize> {
+        let slot = self.fonts.table.iter().position(|f| f.is_none());
+        let index = slot.ok_or(Error::BufferOverflow)?;
+        self.fonts.table[index] = Some(font);
+        Ok(index)
+    }
+
+    /// 番号indexのフォント
+    pub fn font(&self, index: usize) -> Option<&'static dyn Font> {
+        self.fonts.table.get(index).copied().flatten()
+    }
+
+    /// 既定のフォントを、番号indexのフォントにする
+    ///
+    /// 表示中の行も、既定のフォントの文字は描き直す。
+    /// 登録のない番号は、Error::OutOfRange
+    pub fn set_font(&mut self, index: usize) -> Result<()> {
+        if self.font(index).is_none() {
+            return Err(Error::OutOfRange);
+        }
+        self.fonts.default = index;
+        self.start_scroll();
+        self.show_line()
+    }
+
+    /// 既定のフォントの番号
+    pub fn default_font(&self) -> usize {
+        self.fonts.default
+    }
+
+    /// 点滅の半周期(表示・消灯それぞれのtick数)を設定する
+    pub fn set_blink_rate(&mut self, ticks: u32) {
+        self.blink_ticks = ticks.max(1);
+    }
+
+    /// 点滅の半周期(tick数)
+    pub fn blink_rate(&self) -> u32 {
+        self.blink_ticks
+    }
+
+    /// 点滅を1tick進める。表示が変わればtrue
+    ///
+    /// 点滅する文字のない間は、表示の期間のまま止めておく。
+    fn step_blink(&mut self) -> bool {
+        let line = self.line[0..self.line_len].iter();
+        let prev = self.prev[0..self.prev_len].iter();
+        let prev = prev.take(if self.rolling.is_some() { LINE_SIZE } else { 0 });
+        if !line.chain(prev).any(|cell| cell.style.blink) {
+            self.blink_count = 0;
+            self.blink_on = true;
+            return false;
+        }
+        self.blink_count += 1;
+        if self.blink_count < self.blink_ticks {
+            return false;
+        }
+        self.blink_count = 0;
+        self.blink_on = !self.blink_on;
+        true
+    }
+
+    /// 行の切り替えのトランジションを設定する(Noneで解除)
+    ///
+    /// 次の行の切り替えから反映される。
+    pub fn set_transition(&mut self, transition: Option<Transition>) {
+        self.transition = transition;
+    }
+
+    /// トランジションの設定
+    pub fn transition(&self) -> Option<Transition> {
+        self.transition
+    }
+
+    /// トランジションで流している最中か
+    pub fn is_rolling(&self) -> bool {
+        self.rolling.is_some()
+    }
+
+    /// 新しい行を表示する
+    ///
+    /// トランジションが設定されていて、前の行と異なれば、切り替えを流し始める。
+    /// マーキーは、切り替え後の行の先頭から始める。
+    fn set_line(&mut self, line: [Cell; LINE_SIZE], len: usize) -> Result<()> {
+        let changed = self.line[0..self.line_len] != line[0..len];
+        self.rolling = match self.transition {
+            Some(transition) if changed => {
+                self.prev = self.line;
+                self.prev_len = self.line_len;
+                Some(Rolling {
+                    step: 0,
+                    wait: transition.ticks_per_step.max(1) - 1,
+                })
+            }
+            _ => None,
+        };
+        self.line = line;
+        self.line_len = len;
+        self.start_scroll();
+        self.show_line()
+    }
+
+    /// トランジションを1tick進める。表示が変わればtrue
+    fn step_rolling(&mut self) -> bool {
+        let (transition, mut rolling) = match (self.transition, self.rolling) {
+            (Some(transition), Some(rolling)) => (transition, rolling),
+            _ => {
+                self.rolling = None;
+                return false;
+            }
+        };
+        if rolling.wait > 0 {
+            rolling.wait -= 1;
+            self.rolling = Some(rolling);
+            return false;
+        }
+        rolling.step += 1;
+        rolling.wait = transition.ticks_per_step.max(1) - 1;
+        self.rolling = if rolling.step >= self.roll_height() {
+            None
+        } else {
+            Some(rolling)
+        };
+        true
+    }
+
+    /// エスケープシーケンスの1文字を解釈する
+    ///
+    /// 解釈できない文字があれば、そのシーケンスは捨てる。
+    fn parse_escape(&mut self, c: char) -> Result<()> {
+        self.escape = match (self.escape, c) {
+            (Escape::Esc, '[') => Escape::Csi {
+                params: [0; MAX_PARAMS],
+                count: 0,
+            },
+            (Escape::Csi { mut params, count }, '0'..='9') => {
+                if count < MAX_PARAMS {
+                    let digit = c as u16 - '0' as u16;
+                    params[count] = params[count].saturating_mul(10).saturating_add(digit);
+                }
+                Escape::Csi { params, count }
+            }
+            (Escape::Csi { params, count }, ';') => Escape::Csi {
+                params,
+                count: (count + 1).min(MAX_PARAMS),
+            },
+            (Escape::Csi { params, count }, '@'..='~') => {
+                let len = (count + 1).min(MAX_PARAMS);
+                self.escape = Escape::Ground;
+                return self.control(c, &params[0..len]);
+            }
+            _ => Escape::Ground,
+        };
+        Ok(())
+    }
+
+    /// 制御シーケンス(ESC [ params final)を実行する
+    fn control(&mut self, final_char: char, params: &[u16]) -> Result<()> {
+        let param = params[0];
+        match final_char {
+            'G' => self.cursor = (param.max(1) as usize - 1).min(LINE_SIZE),
+            'H' => {
+                let column = params.get(1).copied().unwrap_or(0);
+                self.cursor = (column.max(1) as usize - 1).min(LINE_SIZE);
+            }
+            'J' if param == 2 => {
+                self.buff_len = 0;
+                self.cursor = 0;
+                return self.set_line([Cell::BLANK; LINE_SIZE], 0);
+            }
+            'J' | 'K' => self.erase(param),
+            'm' => params.iter().for_each(|param| self.select_style(*param)),
+            'q' => {
+                // 転送中で送れなかった分は、次のtickで送られる
+                let _ = self.led.set_brightness(param.min(u8::MAX as u16) as u8);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// 書き込み中の行を消去する(EL)
+    fn erase(&mut self, mode: u16) {
+        match mode {
+            0 => self.buff_len = self.buff_len.min(self.cursor),
+            1 => {
+                let end = (self.cursor + 1).min(self.buff_len);
+                self.buff[0..end]
+                    .iter_mut()
+                    .for_each(|cell| *cell = Cell::BLANK);
+            }
+            2 => self.buff_len = 0,
+            _ => {}
+        }
+    }
+
+    /// 属性を変える(SGR) 対応しない属性は無視する
+    fn select_style(&mut self, param: u16) {
+        match param {
+            0 => self.style = Style::DEFAULT,
+            5 => self.style.blink = true,
+            25 => self.style.blink = false,
+            7 => self.style.inverse = true,
+            27 => self.style.inverse = false,
+            10 => self.style.font = None,
+            n @ 11..=19 => self.style.font = Some((n - 10) as u8),
+            _ => {}
+        }
+    }
+
+    /// 書き込み中の行の、カーソルの位置に1文字置く
+    ///
+    /// カーソルが行末より先にあれば、間は空白で埋める。
+    fn put_char(&mut self, c: char) {
+        if self.cursor >= LINE_SIZE {
+            return;
+        }
+        for cell in self.buff[self.buff_len.min(self.cursor)..self.cursor].iter_mut() {
+            *cell = Cell::BLANK;
+        }
+        self.buff[self.cursor] = Cell {
+            c,
+            style: self.style,
+        };
+        self.cursor += 1;
+        self.buff_len = self.buff_len.max(self.cursor);
+    }
+
+    /// 文字を並べる
+    fn typesetter(&self, default_font: usize) -> Typesetter<'_> {
+        Typesetter {
+            layout: &self.layout,
+            fonts: &self.fonts,
+            default_font,
+            blink_on: self.blink_on,
+        }
+    }
+
+    /// 文字列の幅(ドット) 既定のフォントと、文字の配置の設定で並べた幅
+    ///
+    /// 制御文字は含めない。(エスケープシーケンスは解釈しないので、含めないこと)
+    pub fn measure(&self, text: &str) -> u32 {
+        self.measure_text(text, self.fonts.default) as u32
+    }
+
+    /// 文字列の幅(ドット) 番号fontのフォントで並べた幅
+    ///
+    /// 登録のない番号は、Error::OutOfRange
+    pub fn measure_with_font(&self, text: &str, font: usize) -> Result<u32> {
+        if self.font(font).is_none() {
+            return Err(Error::OutOfRange);
+        }
+        Ok(self.measure_text(text, font) as u32)
+    }
+
+    /// 文字列が、パネルの幅に収まるか
+    pub fn fits(&self, text: &str) -> bool {
+        self.measure(text) <= self.led.width() as u32
+    }
+
+    /// 文字列を、既定のフォントを番号fontとして並べた幅
+    fn measure_text(&self, text: &str, font: usize) -> i32 {
+        let cells = text.chars().filter(|c| !c.is_control()).map(|c| Cell {
+            c,
+            style: Style::DEFAULT,
+        });
+        self.typesetter(font).width(cells)
+    }
+
+    /// 行の揃え方を設定する
+    ///
+    /// パネルに収まる行(収まらずに流さない行は左揃え)に使う。
+    pub fn set_align(&mut self, align: Align) -> Result<()> {
+        self.align = align;
+        self.show_line()
+    }
+
+    /// 行の揃え方
+    pub fn align(&self) -> Align {
+        self.align
+    }
+
+    /// パネルに収まらない行の扱いを設定する
+    ///
+    /// 表示中の行は、新しい設定で描き直す。(マーキーは先頭から流し直す)
+    pub fn set_overflow(&mut self, overflow: Overflow) -> Result<()> {
+        self.overflow = overflow;
+        self.start_scroll();
+        self.show_line()
+    }
+
+    /// パネルに収まらない行の扱い
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// 表示中の行の幅(ドット)
+    fn line_width(&self) -> i32 {
+        let line = self.line[0..self.line_len].iter().copied();
+        self.typesetter(self.line_font).width(line)
+    }
+
+    /// 表示中の行の、揃え方による左端の位置
+    fn align_shift(&self) -> i32 {
+        let rest = self.led.width() as i32 - self.line_width();
+        match self.align {
+            _ if rest <= 0 => 0,
+            Align::Left => 0,
+            Align::Center => rest / 2,
+            Align::Right => rest,
+        }
+    }
+
+    /// 表示中の行の既定のフォントを決める
+    ///
+    /// 縮めたフォントの指定があれば、既定のフォントで収まらない行にはそれを使う。
+    fn fit_line(&mut self) {
+        self.line_font = self.fonts.default;
+        if let Overflow::Condense(font) = self.overflow {
+            if self.font(font).is_some() && self.scroll_end() > 0 {
+                self.line_font = font;
+            }
+        }
+    }
+
+    /// トランジションで流す高さ。前の行と新しい行の、最も高い文字の高さ
+    fn roll_height(&self) -> usize {
+        let line = self.line[0..self.line_len].iter();
+        let prev = self.prev[0..self.prev_len].iter();
+        line.chain(prev)
+            .map(|cell| self.fonts.get(cell.style.font, self.line_font).height())
+            .max()
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    /// 行が流れ切るまでのずれ(末尾がパネルの右端に来る位置)
+    fn scroll_end(&self) -> i32 {
+        self.line_width() - self.led.width() as i32
+    }
+
+    /// 表示中の行の既定のフォントを決め直し、マーキーを先頭から開始する
+    ///
+    /// パネルに収まる行と、Overflow::Clipでは流さない。
+    fn start_scroll(&mut self) {
+        self.fit_line();
+        let marquee = match self.overflow {
+            Overflow::Clip => None,
+            _ => self.marquee,
+        };
+        self.scroll = match marquee {
+            Some(marquee) if self.scroll_end() > 0 && marquee.loops != Some(0) => Some(Scroll {
+                offset: 0,
+                forward: true,
+                wait: marquee.pause_start + marquee.ticks_per_step.max(1) - 1,
+                loops: 0,
+            }),
+            _ => None,
+        };
+    }
+
+    /// マーキーを1tick進める。表示が変わればtrue
+    fn step_scroll(&mut self) -> bool {
+        let (marquee, mut scroll) = match (self.marquee, self.scroll) {
+            (Some(marquee), Some(scroll)) => (marquee, scroll),
+            _ => return false,
+        };
+        if scroll.wait > 0 {
+            scroll.wait -= 1;
+            self.scroll = Some(scroll);
+            return false;
+        }
+        let end = self.scroll_end();
+        let period = self.line_width() + marquee.gap as i32;
+        scroll.offset += if scroll.forward { 1 } else { -1 };
+        scroll.wait = marquee.ticks_per_step.max(1) - 1;
+        let mut wrapped = false;
+        if marquee.ping_pong {
+            if scroll.forward && scroll.offset >= end {
+                scroll.forward = false;
+                scroll.wait += marquee.pause_end;
+            } else if !scroll.forward && scroll.offset <= 0 {
+                scroll.forward = true;
+                wrapped = true;
+            }
+        } else if scroll.offset >= period {
+            scroll.offset = 0;
+            wrapped = true;
+        } else if scroll.offset == end {
+            scroll.wait += marquee.pause_end;
+        }
+        if wrapped {
+            scroll.loops += 1;
+            if Some(scroll.loops) == marquee.loops {
+                self.scroll = None; // 先頭を表示して止まる
+                return true;
+            }
+            scroll.wait += marquee.pause_start;
+        }
+        self.scroll = Some(scroll);
+        true
+    }
+
+    /// 表示中の行を、マーキーのずれを反映して描き、表示する
+    fn show_line(&mut self) -> Result<()> {
+        let (offset, period) = match (self.marquee, self.scroll) {
+            (Some(marquee), Some(scroll)) if !marquee.ping_pong => {
+                (scroll.offset, Some(self.line_width() + marquee.gap as i32))
+            }
+            (_, Some(scroll)) => (scroll.offset, None),
+            _ => (-self.align_shift(), None),
+        };
+        // トランジション中は、前の行と合わせて描く
+        let rolling = match (self.transition, self.rolling) {
+            (Some(transition), Some(rolling)) => Some((transition, rolling.step)),
+            _ => None,
+        };
+        let height = self.roll_height();
+        let width = self.led.width() as i32;
+        let line = &self.line[0..self.line_len];
+        let prev = &self.prev[0..self.prev_len];
+        let typesetter = Typesetter {
+            layout: &self.layout,
+            fonts: &self.fonts,
+            default_font: self.line_font,
+            blink_on: self.blink_on,
+        };
+        let vram = self.led.video_ram_mut();
+        vram.clear();
+        // 繰り返す場合は、次の周の先頭も描く
+        let bases = [Some(-offset), period.map(|p| p - offset)];
+        for base in bases.iter().flatten() {
+            let (transition, step) = match rolling {
+                Some(rolling) => rolling,
+                None => {
+                    typesetter.draw(vram, *base, width, line, |_, _| Some((0, ALL_ROWS)));
+                    continue;
+                }
+            };
+            // 前の行・新しい行それぞれの、行の高さに収まる部分(y, 行の範囲)
+            let rest = height - step;
+            let (old_part, new_part) = match transition.roll {
+                Roll::Up => ((0, step..height), (rest as i32, 0..step)),
+                Roll::Down => ((step as i32, 0..rest), (0, rest..height)),
+            };
+            // 同じ位置の同じ文字は流さず、新しい行の側で描く
+            let fixed = |x: i32, cell: Cell, other: &[Cell]| {
+                transition.changed_only && typesetter.cell_at(other, x) == Some(cell)
+            };
+            typesetter.draw(vram, *base, width, prev, |x, cell| {
+                if fixed(x, cell, line) {
+                    None
+                } else {
+                    Some(old_part.clone())
+                }
+            });
+            typesetter.draw(vram, *base, width, line, |x, cell| {
+                if fixed(x, cell, prev) {
+                    Some((0, ALL_ROWS))
+                } else {
+                    Some(new_part.clone())
+                }
+            });
+        }
+        let result = self.led.present();
+        self.unsent = result.is_err();
+        result
     }
 }
 
 use core::fmt;
-use core::fmt::Write;
-use misakifont::font48::FONT48;
-//use misakifont::font88::FONT88;
+use core::ops::Range;
+
+/// フォントの全ての行
+const ALL_ROWS: Range<usize> = 0..usize::MAX;
+
+/// 文字列を、配置の設定とフォントに従って並べる
+struct Typesetter<'a> {
+    layout: &'a TextLayout,
+    fonts: &'a Fonts,
+    default_font: usize, // フォントの指定のない文字のフォント
+    blink_on: bool,      // 点滅する文字を描くか
+}
+
+impl<'a> Typesetter<'a> {
+    /// 各文字の配置
+    fn glyphs<I: IntoIterator<Item = Cell>>(&self, text: I) -> Glyphs<'_, I::IntoIter> {
+        Glyphs {
+            text: text.into_iter(),
+            layout: self.layout,
+            fonts: self.fonts,
+            default_font: self.default_font,
+            x: 0,
+            prev: None,
+        }
+    }
+
+    /// 文字列の幅(ドット)
+    fn width<I: IntoIterator<Item = Cell>>(&self, text: I) -> i32 {
+        self.glyphs(text).width()
+    }
+
+    /// 先頭からxドットの位置に置いた文字。なければNone
+    fn cell_at(&self, text: &[Cell], x: i32) -> Option<Cell> {
+        self.glyphs(text.iter().copied())
+            .find(|glyph| glyph.x == x)
+            .map(|glyph| glyph.cell)
+    }
+
+    /// 文字列textを、baseを左端として描く。パネルの範囲外の文字は描かない
+    ///
+    /// 各文字を描く部分は、part(先頭からの位置, 文字)で、(y, フォントの行の範囲)として決める。
+    /// Noneの文字、点滅の消灯期間の文字は描かない。
+    /// 反転の文字は、文字の幅(span)の範囲を反転して描く。
+    fn draw<F>(&self, vram: &mut VideoRam, base: i32, width: i32, text: &[Cell], mut part: F)
+    where
+        F: FnMut(i32, Cell) -> Option<(i32, Range<usize>)>,
+    {
+        for glyph in self.glyphs(text.iter().copied()) {
+            let x = base + glyph.x;
+            if x + (glyph.width as i32) <= 0 || x >= width {
+                continue;
+            }
+            if glyph.cell.style.blink && !self.blink_on {
+                continue;
+            }
+            if let Some((y, rows)) = part(glyph.x, glyph.cell) {
+                let len = glyph.bitmap.len();
+                let rows = rows.start.min(len)..rows.end.min(len);
+                let bitmap = &glyph.bitmap[rows];
+                if glyph.cell.style.inverse {
+                    for (dy, line) in bitmap.iter().enumerate() {
+                        let inverted = [!line & glyph.span];
+                        vram.draw_bitmap(x, y + dy as i32, glyph.width as u32, &inverted);
+                    }
+                } else {
+                    vram.draw_bitmap(x, y, glyph.width as u32, bitmap);
+                }
+            }
+        }
+    }
+}
+
+/// 幅widthのビットマップの、列from〜toのマスク
+fn column_mask(width: usize, from: i32, to: i32) -> u8 {
+    let cols = from.max(0)..to.min(width as i32);
+    cols.fold(0, |mask, col| mask | 1 << (width as i32 - 1 - col))
+}
+
+/// 配置した1文字
+struct Glyph {
+    x: i32, // 先頭からの位置(ビットマップの左端)
+    cell: Cell,
+    bitmap: &'static [u8],
+    width: usize, // ビットマップの幅
+    span: u8,     // 文字の幅の列(ビットマップと同じ並び)
+}
+
+/// 文字列の各文字の配置
+///
+/// プロポーショナルでは、ビットマップの左の空白の分、位置が左へずれる。
+struct Glyphs<'a, I> {
+    text: I,
+    layout: &'a TextLayout,
+    fonts: &'a Fonts,
+    default_font: usize,
+    x: i32, // 次の文字の左端
+    prev: Option<char>,
+}
+
+impl<'a, I: Iterator<Item = Cell>> Glyphs<'a, I> {
+    /// 残りの文字を並べ、全体の幅を返す
+    fn width(mut self) -> i32 {
+        while self.next().is_some() {}
+        match (self.layout.pitch, self.prev) {
+            // 末尾の文字間の空白は含めない
+            (Pitch::Proportional, Some(_)) => self.x - self.layout.spacing as i32,
+            _ => self.x,
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item = Cell>> Iterator for Glyphs<'a, I> {
+    type Item = Glyph;
 
-impl fmt::Write for DisplayLed<'_> {
+    fn next(&mut self) -> Option<Glyph> {
+        let cell = self.text.next()?;
+        let font = self.fonts.get(cell.style.font, self.default_font);
+        let bitmap = font.glyph(cell.c);
+        let width = font.width(cell.c).min(MAX_GLYPH_WIDTH);
+        let (x, left, advance) = match self.layout.pitch {
+            Pitch::Fixed => (self.x, 0, width as i32),
+            Pitch::Proportional => {
+                let kern = self.prev.map_or(0, |p| self.layout.kerning(p, cell.c));
+                let (left, advance) = self.layout.metrics(cell.c, bitmap, width);
+                let advance = advance + self.layout.spacing as i32;
+                (self.x + kern - left, left, advance)
+            }
+        };
+        self.x = x + left + advance;
+        self.prev = Some(cell.c);
+        Some(Glyph {
+            x,
+            cell,
+            bitmap,
+            width,
+            span: column_mask(width, left, left + advance),
+        })
+    }
+}
+
+impl<P: LedPanel> fmt::Write for DisplayLed<P> {
+    /// 送信の失敗は、エラーとしない
+    ///
+    /// 行は組み立てたまま残し、次のtick()か、次の行の表示で送り直す。
     fn write_str(&mut self, s: &str) -> fmt::Result {
-        if s.len() == 0 {
+        if s.is_empty() {
             return Ok(());
         }
         let mut is_output = false;
         for c in s.chars() {
+            if self.escape != Escape::Ground {
+                let _ = self.parse_escape(c);
+                continue;
+            }
             match c {
                 '\n' => {
                     is_output = true;
                 }
-                cc if cc.is_ascii_control() => {}
-                cc => {
-                    if self.buff_len < 50 {
-                        self.buff[self.buff_len] = cc as u8;
-                        self.buff_len += 1;
-                    }
+                '\r' => {
+                    self.cursor = 0;
                 }
+                '\x08' => {
+                    self.cursor = self.cursor.saturating_sub(1);
+                }
+                ESC => {
+                    self.escape = Escape::Esc;
+                }
+                cc if cc.is_ascii_control() => {}
+                cc => self.put_char(cc),
             }
         }
 
-        if is_output == true {
-            self.led.clear();
-            for (i, c) in self.buff[0..self.buff_len].iter().enumerate() {
-                let font = FONT48.get_char(*c);
-                self.led.draw_bitmap((i * 4) as i32, 0, 4, font);
-                while let Err(_) = self.led.flash_led() {}
-            }
+        if is_output {
+            let len = self.buff_len;
             self.buff_len = 0;
+            self.cursor = 0;
+            let _ = self.set_line(self.buff, len);
         }
 
         Ok(())
     }
 }
 
-pub fn print_led_fmt(disp: &mut DisplayLed, args: fmt::Arguments) {
-    disp.write_fmt(args).unwrap()
+/// print_led!の本体。DisplayRgb等、fmt::Writeを実装したディスプレイにも使える
+///
+/// 失敗してもpanicしない。(print_led!は結果を捨てる)
+pub fn print_led_fmt<W: fmt::Write>(disp: &mut W, args: fmt::Arguments) -> fmt::Result {
+    disp.write_fmt(args)
 }
 
 #[macro_export]
 macro_rules! print_led {
     ($disp:ident, $($arg:tt)*) => {
-        display_led::print_led_fmt(&mut $disp, format_args!($($arg)*));
+        let _ = display_led::print_led_fmt(&mut $disp, format_args!($($arg)*));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::RecordingTransport;
+    use core::fmt::Write;
+
+    /// fail_at回目のbegin()だけ、転送エラーを返す送信路
+    struct FlakyTransport {
+        inner: RecordingTransport,
+        begins: usize,
+        fail_at: usize,
+    }
+
+    impl Transport for FlakyTransport {
+        fn begin(&mut self) -> Result<()> {
+            self.begins += 1;
+            if self.begins == self.fail_at {
+                return Err(Error::TransferError);
+            }
+            self.inner.begin()
+        }
+        fn select(&mut self) -> Result<()> {
+            self.inner.select()
+        }
+        fn write(&mut self, words: &[u16]) -> Result<()> {
+            self.inner.write(words)
+        }
+        fn deselect(&mut self) -> Result<()> {
+            self.inner.deselect()
+        }
+        fn flush(&mut self) -> Result<()> {
+            self.inner.flush()
+        }
+        fn is_busy(&self) -> bool {
+            false
+        }
+        fn is_pending(&self) -> bool {
+            false
+        }
+    }
+
+    /// 点灯しているドットがあるか
+    fn lit(vram: &VideoRam) -> bool {
+        let (w, h) = (vram.width() as i32, vram.height() as i32);
+        (0..h).any(|y| (0..w).any(|x| vram.get(x, y)))
+    }
+
+    /// 全ての文字が、幅4ドットで左端1列のみ点灯するテスト用フォント
+    struct Bar;
+
+    static BAR: Bar = Bar;
+
+    impl Font for Bar {
+        fn height(&self) -> usize {
+            8
+        }
+        fn width(&self, _c: char) -> usize {
+            4
+        }
+        fn glyph(&self, c: char) -> &[u8] {
+            match c {
+                ' ' => &[0x00; 8],
+                _ => &[0x08; 8],
+            }
+        }
+    }
+
+    /// Barと同じく左端1列のみ点灯する、幅2ドット(Narrow)・幅8ドット(Wide)のテスト用フォント
+    struct Narrow;
+    struct Wide;
+
+    static NARROW: Narrow = Narrow;
+    static WIDE: Wide = Wide;
+
+    impl Font for Narrow {
+        fn height(&self) -> usize {
+            8
+        }
+        fn width(&self, _c: char) -> usize {
+            2
+        }
+        fn glyph(&self, _c: char) -> &[u8] {
+            &[0x02; 8]
+        }
+    }
+
+    impl Font for Wide {
+        fn height(&self) -> usize {
+            8
+        }
+        fn width(&self, _c: char) -> usize {
+            8
+        }
+        fn glyph(&self, _c: char) -> &[u8] {
+            &[0x80; 8]
+        }
+    }
+
+    /// Barフォントを既定とした、32*8パネルのディスプレイ
+    fn bar_display() -> DisplayLed<Matrix<RecordingTransport>> {
+        let mut d = DisplayLed::with_transport(RecordingTransport::new());
+        let index = d.add_font(&BAR).unwrap();
+        d.set_font(index).unwrap();
+        d
+    }
+
+    /// 1行目の点灯しているx座標
+    fn columns<P: LedPanel>(d: &DisplayLed<P>) -> Vec<i32> {
+        let vram = d.matrix().video_ram();
+        (0..vram.width() as i32)
+            .filter(|&x| vram.get(x, 0))
+            .collect()
+    }
+
+    /// x列の点灯している行数
+    fn rows_lit<P: LedPanel>(d: &DisplayLed<P>, x: i32) -> usize {
+        let vram = d.matrix().video_ram();
+        (0..vram.height() as i32)
+            .filter(|&y| vram.get(x, y))
+            .count()
+    }
+
+    #[test]
+    fn transfer_error_is_retried_on_tick() {
+        let transport = FlakyTransport {
+            inner: RecordingTransport::new(),
+            begins: 0,
+            fail_at: 2, // 1回目は初期化
+        };
+        let mut d = DisplayLed::with_transport(transport);
+        let sent = d.matrix().transport().inner.packets().count();
+        assert!(print_led_fmt(&mut d, format_args!("AB\n")).is_ok());
+        assert_eq!(d.matrix().transport().inner.packets().count(), sent);
+        // 組み立てた行は残っていて、次のtickで送られる
+        d.tick();
+        assert!(d.matrix().transport().inner.packets().count() > sent);
+        assert!(lit(d.matrix().video_ram()));
+        let flushes = d.matrix().transport().inner.flush_count();
+        d.tick();
+        assert_eq!(d.matrix().transport().inner.flush_count(), flushes);
+        writeln!(d, "C").unwrap();
+    }
+
+    #[test]
+    fn marquee_scrolls_and_stops_after_loops() {
+        let mut d = bar_display();
+        d.set_marquee(Some(Marquee::new(1).with_loops(1))).unwrap();
+        writeln!(d, "0123456789").unwrap(); // 40ドット
+        assert!(d.is_scrolling());
+        assert_eq!(columns(&d)[0..2], [0, 4]);
+        d.tick();
+        assert_eq!(columns(&d)[0..2], [3, 7]);
+        let mut ticks = 1;
+        while d.is_scrolling() {
+            d.tick();
+            ticks += 1;
+            assert!(ticks < 1000);
+        }
+        assert_eq!(ticks, 40);
+        assert_eq!(columns(&d)[0..2], [0, 4]);
+    }
+
+    #[test]
+    fn marquee_with_zero_loops_is_static() {
+        let mut d = bar_display();
+        d.set_marquee(Some(Marquee::new(1).with_loops(0))).unwrap();
+        writeln!(d, "0123456789").unwrap();
+        assert!(!d.is_scrolling());
+        let before = columns(&d);
+        assert_eq!(before[0..2], [0, 4]);
+        for _ in 0..50 {
+            d.tick();
+        }
+        assert_eq!(columns(&d), before);
+    }
+
+    #[test]
+    fn transition_rolls_to_new_line() {
+        let mut d = bar_display();
+        writeln!(d, "A").unwrap();
+        d.set_transition(Some(Transition::new(Roll::Up, 1)));
+        writeln!(d, " A").unwrap();
+        assert!(d.is_rolling());
+        for _ in 0..4 {
+            d.tick();
+        }
+        // 前の行が上へ抜け、新しい行が下から入る途中
+        assert!(rows_lit(&d, 0) > 0 && rows_lit(&d, 0) < 8);
+        assert!(rows_lit(&d, 4) > 0 && rows_lit(&d, 4) < 8);
+        assert!(d.matrix().video_ram().get(0, 0));
+        assert!(d.matrix().video_ram().get(4, 7));
+        let mut ticks = 4;
+        while d.is_rolling() {
+            d.tick();
+            ticks += 1;
+            assert!(ticks < 100);
+        }
+        assert_eq!(rows_lit(&d, 0), 0);
+        assert_eq!(rows_lit(&d, 4), 8);
+    }
+
+    #[test]
+    fn transition_changed_only() {
+        let mut d = bar_display();
+        writeln!(d, "A ").unwrap();
+        d.set_transition(Some(Transition::new(Roll::Down, 1).with_changed_only()));
+        writeln!(d, "AB").unwrap();
+        assert!(d.is_rolling());
+        for _ in 0..4 {
+            d.tick();
+        }
+        // 変化していない文字は、そのまま
+        assert_eq!(rows_lit(&d, 0), 8);
+        assert!(rows_lit(&d, 4) > 0 && rows_lit(&d, 4) < 8);
+        assert!(d.matrix().video_ram().get(4, 0));
+        while d.is_rolling() {
+            d.tick();
+        }
+        assert_eq!(rows_lit(&d, 4), 8);
+    }
+
+    #[test]
+    fn proportional_layout() {
+        static WIDTHS: [GlyphWidth; 1] = [GlyphWidth {
+            c: 'W',
+            left: 0,
+            width: 3,
+        }];
+        static KERNING: [Kerning; 1] = [Kerning {
+            left: 'A',
+            right: 'B',
+            adjust: -1,
+        }];
+        let mut d = bar_display();
+        d.set_layout(TextLayout::proportional()).unwrap();
+        writeln!(d, "AAA").unwrap();
+        assert_eq!(columns(&d), [0, 2, 4]);
+        writeln!(d, "A A").unwrap();
+        assert_eq!(columns(&d), [0, 5]);
+        let layout = TextLayout::proportional()
+            .with_widths(&WIDTHS)
+            .with_kerning(&KERNING);
+        d.set_layout(layout).unwrap();
+        writeln!(d, "WA").unwrap();
+        assert_eq!(columns(&d), [0, 4]);
+        writeln!(d, "AB").unwrap();
+        assert_eq!(columns(&d), [0, 1]);
+        d.set_layout(TextLayout::fixed()).unwrap();
+        assert_eq!(columns(&d), [0, 4]);
+    }
+
+    #[test]
+    fn fonts_per_run() {
+        let mut d = bar_display();
+        let wide = d.add_font(&WIDE).unwrap();
+        assert_eq!(wide, 2);
+        writeln!(d, "A\x1b[12mA\x1b[10mA").unwrap();
+        assert_eq!(columns(&d), [0, 4, 12]);
+        // 属性は改行後も続く
+        writeln!(d, "\x1b[12mA").unwrap();
+        writeln!(d, "AA").unwrap();
+        assert_eq!(columns(&d), [0, 8]);
+        writeln!(d, "\x1b[0mAA").unwrap();
+        assert_eq!(columns(&d), [0, 4]);
+        d.set_font(wide).unwrap();
+        assert_eq!(d.default_font(), wide);
+        assert_eq!(columns(&d), [0, 8]);
+        assert_eq!(d.set_font(9), Err(Error::OutOfRange));
+        for _ in 3..MAX_FONTS {
+            d.add_font(&NARROW).unwrap();
+        }
+        assert_eq!(d.add_font(&NARROW), Err(Error::BufferOverflow));
+    }
+
+    #[test]
+    fn control_sequences() {
+        let mut d = bar_display();
+        writeln!(d, "A A\r ").unwrap();
+        assert_eq!(columns(&d), [8]);
+        writeln!(d, "A\x1b[3GA").unwrap();
+        assert_eq!(columns(&d), [0, 8]);
+        writeln!(d, "ABC\x1b[2G\x1b[0K").unwrap();
+        assert_eq!(columns(&d), [0]);
+        writeln!(d, "AB\x08\x1b[K").unwrap();
+        assert_eq!(columns(&d), [0]);
+        writeln!(d, "\x1b[99zA").unwrap();
+        assert_eq!(columns(&d), [0]);
+        writeln!(d, "\x1b[7mA\x1b[27m").unwrap();
+        assert_eq!(columns(&d), [1, 2, 3]);
+        writeln!(d, "AB\x1b[2J").unwrap();
+        assert!(columns(&d).is_empty());
+        writeln!(d, "\x1b[5q").unwrap();
+        assert_eq!(d.matrix().brightness(0), Ok(5));
+    }
+
+    #[test]
+    fn blink_attribute() {
+        let mut d = bar_display();
+        d.set_blink_rate(2);
+        writeln!(d, "A\x1b[5mA\x1b[25mA").unwrap();
+        assert_eq!(columns(&d), [0, 4, 8]);
+        d.tick();
+        d.tick();
+        assert_eq!(columns(&d), [0, 8]);
+        d.tick();
+        d.tick();
+        assert_eq!(columns(&d), [0, 4, 8]);
+    }
+
+    #[test]
+    fn align_and_measure() {
+        let mut d = bar_display();
+        assert_eq!(d.measure("AB"), 8);
+        assert!(d.fits("ABCDEFGH"));
+        assert!(!d.fits("ABCDEFGHI"));
+        d.add_font(&NARROW).unwrap();
+        assert_eq!(d.measure_with_font("AB", 2), Ok(4));
+        assert_eq!(d.measure_with_font("AB", 9), Err(Error::OutOfRange));
+        writeln!(d, "A").unwrap();
+        d.set_align(Align::Center).unwrap();
+        assert_eq!(columns(&d), [14]);
+        d.set_align(Align::Right).unwrap();
+        assert_eq!(columns(&d), [28]);
+        // 収まらない行は左揃え
+        writeln!(d, "ABCDEFGHI").unwrap();
+        assert_eq!(columns(&d)[0], 0);
+    }
+
+    #[test]
+    fn overflow_policy() {
+        let mut d = bar_display();
+        let narrow = d.add_font(&NARROW).unwrap();
+        d.set_marquee(Some(Marquee::new(1))).unwrap();
+        d.set_overflow(Overflow::Clip).unwrap();
+        writeln!(d, "ABCDEFGHI").unwrap();
+        assert!(!d.is_scrolling());
+        assert_eq!(columns(&d).len(), 8);
+        d.set_overflow(Overflow::Condense(narrow)).unwrap();
+        assert!(!d.is_scrolling());
+        assert_eq!(columns(&d), (0..9).map(|i| i * 2).collect::<Vec<_>>());
+        // 収まる行は、既定のフォントのまま
+        writeln!(d, "AB").unwrap();
+        assert_eq!(columns(&d), [0, 4]);
+        d.set_overflow(Overflow::Marquee).unwrap();
+        writeln!(d, "ABCDEFGHI").unwrap();
+        assert!(d.is_scrolling());
     }
 }
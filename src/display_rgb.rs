@@ -0,0 +1,130 @@
+//! rgb_matrixをキャラクタディスプレイとして使用する
+//! DisplayLedと同じ使い方で、文字色・背景色を指定できる。
+//!
+//! 文字の処理(フォント・エスケープシーケンス・マーキー等)は、DisplayLedがそのまま行う。
+//! RgbMatrixのvideo_ramに描いた文字を、文字色・背景色で塗って表示する。
+
+use core::fmt;
+
+use super::display_led::DisplayLed;
+#[cfg(target_os = "none")]
+use super::rgb_matrix::RgbPanelConfig;
+use super::rgb_matrix::{Rgb, RgbMatrix};
+use super::transport::PixelTransport;
+#[cfg(target_os = "none")]
+use super::ws2812_dma::Ws2812Dma;
+#[cfg(target_os = "none")]
+use stm32f4::stm32f401;
+
+///カラーディスプレイドライバ
+pub struct DisplayRgb<T: PixelTransport> {
+    display: DisplayLed<RgbMatrix<T>>,
+}
+
+#[cfg(target_os = "none")]
+impl<'a> DisplayRgb<Ws2812Dma<'a>> {
+    /// SPI1+DMA2経由のWS2812パネル(32*8)で初期化する
+    pub fn new(device: &'a stm32f401::Peripherals) -> Self {
+        Self::with_config(device, RgbPanelConfig::default())
+    }
+
+    /// SPI1+DMA2経由のWS2812パネルを、指定のパネル構成で初期化する
+    pub fn with_config(device: &'a stm32f401::Peripherals, config: RgbPanelConfig) -> Self {
+        Self::from_matrix(RgbMatrix::with_config(Ws2812Dma::new(device), config))
+    }
+}
+
+impl<T: PixelTransport> DisplayRgb<T> {
+    /// 任意の送信路で初期化する
+    pub fn with_transport(transport: T) -> Self {
+        Self::from_matrix(RgbMatrix::new(transport))
+    }
+
+    /// 初期化済みのRgbMatrixから作る
+    pub fn from_matrix(led: RgbMatrix<T>) -> Self {
+        let mut display = DisplayRgb {
+            display: DisplayLed::from_panel(led),
+        };
+        display.clear();
+        display
+    }
+
+    /// RGB Matrix LEDへの参照
+    pub fn matrix(&self) -> &RgbMatrix<T> {
+        self.display.matrix()
+    }
+
+    /// RGB Matrix LEDへの可変参照
+    /// 輝度設定等は、こちらから直接行う。
+    pub fn matrix_mut(&mut self) -> &mut RgbMatrix<T> {
+        self.display.matrix_mut()
+    }
+
+    /// 文字表示への参照
+    pub fn display(&self) -> &DisplayLed<RgbMatrix<T>> {
+        &self.display
+    }
+
+    /// 文字表示への可変参照
+    /// フォント・マーキー・揃え等の設定は、こちらから行う。
+    pub fn display_mut(&mut self) -> &mut DisplayLed<RgbMatrix<T>> {
+        &mut self.display
+    }
+
+    /// 文字色を設定する。次の表示から反映される
+    pub fn set_color(&mut self, color: Rgb) {
+        self.matrix_mut().set_pen(color);
+    }
+
+    /// 背景色を設定する。次の表示から反映される
+    pub fn set_background(&mut self, background: Rgb) {
+        self.matrix_mut().set_paper(background);
+    }
+
+    /// 時間経過の通知(マーキー・点滅等の進行)
+    pub fn tick(&mut self) {
+        self.display.tick();
+    }
+
+    /// 全体を背景色で消去する
+    pub fn clear(&mut self) {
+        let background = self.matrix().paper();
+        self.matrix_mut().fill(background);
+        self.display.clear();
+    }
+}
+
+impl<T: PixelTransport> fmt::Write for DisplayRgb<T> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.display.write_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::RecordingPixels;
+    use core::fmt::Write;
+
+    #[test]
+    fn text_is_painted_with_colors() {
+        let mut d = DisplayRgb::with_transport(RecordingPixels::new());
+        d.set_color(Rgb::CYAN);
+        d.set_background(Rgb::BLUE);
+        writeln!(d, "A").unwrap();
+        let pixels = d.matrix().transport().pixels();
+        assert_eq!(pixels.len(), 256);
+        assert!(pixels.iter().any(|&p| p == Rgb::CYAN.scale(2)));
+        assert!(pixels
+            .iter()
+            .all(|&p| p == Rgb::CYAN.scale(2) || p == Rgb::BLUE.scale(2)));
+
+        // 色の変更は、変化していない文字も塗り直す
+        d.set_color(Rgb::RED);
+        d.tick();
+        writeln!(d, "A").unwrap();
+        let pixels = d.matrix().transport().pixels();
+        assert!(pixels.iter().any(|&p| p == Rgb::RED.scale(2)));
+        assert!(!pixels.iter().any(|&p| p == Rgb::CYAN.scale(2)));
+    }
+}
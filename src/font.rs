@@ -0,0 +1,160 @@
+//! ビットマップフォントの共通インターフェース
+//!
+//! 1文字は1行1バイトのビットマップで、幅(8以下)の分をLSBより詰める。
+//! (VideoRam::draw_bitmapと同じ並び)
+//!
+//! 美咲フォントは、半角(4*8)・全角(8*8)の組み合わせ(MISAKI)と、
+//! 半角のみ(MISAKI_HALF)・全角のみ(MISAKI_FULL)を用意してある。
+//! 全角フォントは、EUC-JPの文字コードで引く。(JIS X 0208の文字のみ)
+
+use misakifont::font48::FONT48;
+use misakifont::font88::FONT88;
+
+use crate::jis0208;
+
+/// ビットマップの最大幅(ドット)
+pub const MAX_GLYPH_WIDTH: usize = 8;
+
+/// 美咲フォント 半角の幅(ドット)
+const HALF_WIDTH: usize = 4;
+/// 美咲フォント 全角の幅(ドット)
+const FULL_WIDTH: usize = 8;
+/// 美咲フォントの高さ(ドット)
+const MISAKI_HEIGHT: usize = 8;
+
+/// 全角フォントにない文字の代わり(〓 EUC-JP)
+const UNDEFINED_FULL: u16 = 0xA2AE;
+/// 半角フォントにない文字の代わり
+const UNDEFINED_HALF: u8 = b'?';
+
+/// ビットマップフォント
+pub trait Font {
+    /// 文字の高さ(ドット)
+    fn height(&self) -> usize;
+    /// 文字cのビットマップの幅(ドット、MAX_GLYPH_WIDTH以下)
+    fn width(&self, c: char) -> usize;
+    /// 文字cのビットマップ(height()行)
+    ///
+    /// フォントにない文字は、代わりの文字のビットマップを返すこと。
+    fn glyph(&self, c: char) -> &[u8];
+}
+
+/// 半角フォントの文字コード。ASCIIと半角カナ(JIS X 0201の0xA1〜0xDF)
+fn half_code(c: char) -> Option<u8> {
+    match c as u32 {
+        code @ 0x00..=0x7F => Some(code as u8),
+        code @ 0xFF61..=0xFF9F => Some((code - 0xFF61 + 0xA1) as u8),
+        _ => None,
+    }
+}
+
+/// 全角フォントの文字コード(EUC-JP)。JIS X 0208にない文字はNone
+fn full_code(c: char) -> Option<u16> {
+    jis0208::to_euc(c)
+}
+
+/// 美咲フォント 半角と全角の組み合わせ
+///
+/// ASCII・半角カナは半角、それ以外(かな・漢字・全角記号等)は全角で表示する。
+pub struct Misaki;
+
+/// 美咲フォント 半角と全角の組み合わせ
+pub static MISAKI: Misaki = Misaki;
+
+impl Font for Misaki {
+    fn height(&self) -> usize {
+        MISAKI_HEIGHT
+    }
+
+    fn width(&self, c: char) -> usize {
+        match half_code(c) {
+            Some(_) => HALF_WIDTH,
+            None => FULL_WIDTH,
+        }
+    }
+
+    fn glyph(&self, c: char) -> &[u8] {
+        match half_code(c) {
+            Some(code) => FONT48.get_char(code),
+            None => FONT88.get_char(full_code(c).unwrap_or(UNDEFINED_FULL)),
+        }
+    }
+}
+
+/// 美咲フォント 半角のみ
+///
+/// 半角にない文字は、'?'で表示する。
+pub struct MisakiHalf;
+
+/// 美咲フォント 半角のみ
+pub static MISAKI_HALF: MisakiHalf = MisakiHalf;
+
+impl Font for MisakiHalf {
+    fn height(&self) -> usize {
+        MISAKI_HEIGHT
+    }
+
+    fn width(&self, _c: char) -> usize {
+        HALF_WIDTH
+    }
+
+    fn glyph(&self, c: char) -> &[u8] {
+        FONT48.get_char(half_code(c).unwrap_or(UNDEFINED_HALF))
+    }
+}
+
+/// 美咲フォント 全角のみ
+///
+/// ASCIIの文字は、対応する全角の文字(全角英数・全角空白)で表示する。
+pub struct MisakiFull;
+
+/// 美咲フォント 全角のみ
+pub static MISAKI_FULL: MisakiFull = MisakiFull;
+
+impl Font for MisakiFull {
+    fn height(&self) -> usize {
+        MISAKI_HEIGHT
+    }
+
+    fn width(&self, _c: char) -> usize {
+        FULL_WIDTH
+    }
+
+    fn glyph(&self, c: char) -> &[u8] {
+        let c = match c {
+            ' ' => '\u{3000}',
+            '!'..='~' => core::char::from_u32(c as u32 - 0x21 + 0xFF01).unwrap_or(c),
+            _ => c,
+        };
+        FONT88.get_char(full_code(c).unwrap_or(UNDEFINED_FULL))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_width_glyph_uses_euc_code() {
+        assert_eq!(full_code('あ'), Some(0xA4A2));
+        assert_eq!(MISAKI.glyph('あ'), FONT88.get_char(0xA4A2));
+        assert_eq!(MISAKI_FULL.glyph('あ'), FONT88.get_char(0xA4A2));
+        assert_ne!(MISAKI.glyph('あ'), MISAKI.glyph('\u{1F600}'));
+    }
+
+    #[test]
+    fn unmapped_glyph_is_geta() {
+        let geta = FONT88.get_char(UNDEFINED_FULL);
+        assert_eq!(MISAKI.glyph('〓'), geta);
+        assert_eq!(MISAKI.glyph('\u{1F600}'), geta);
+        assert_eq!(MISAKI.glyph('Ω'), FONT88.get_char(0xA6B8));
+        assert_eq!(MISAKI.glyph('\u{0100}'), geta); // JIS X 0208にない
+    }
+
+    #[test]
+    fn full_only_maps_ascii_to_full_width() {
+        assert_eq!(MISAKI_FULL.glyph('A'), FONT88.get_char(0xA3C1));
+        assert_eq!(MISAKI_FULL.glyph(' '), FONT88.get_char(0xA1A1));
+        assert_eq!(MISAKI_FULL.width('A'), FULL_WIDTH);
+    }
+}
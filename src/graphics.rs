@@ -0,0 +1,104 @@
+//! embedded-graphicsへの対応
+//!
+//! MatrixにDrawTarget(BinaryColor)、RgbMatrixにDrawTarget(Rgb888)を実装し、
+//! embedded-graphicsの図形・文字・画像等をフレームバッファへ描けるようにする。
+//! 表示には、従来通りpresent()を呼ぶこと。
+
+use core::convert::Infallible;
+
+use embedded_graphics::draw_target::DrawTarget;
+use embedded_graphics::geometry::{OriginDimensions, Size};
+use embedded_graphics::pixelcolor::{BinaryColor, Rgb888, RgbColor};
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::Pixel;
+
+use crate::matrix_led::Matrix;
+use crate::rgb_matrix::{Rgb, RgbMatrix};
+use crate::transport::{PixelTransport, Transport};
+
+impl<T: Transport> OriginDimensions for Matrix<T> {
+    fn size(&self) -> Size {
+        Size::new(self.width() as u32, self.height() as u32)
+    }
+}
+
+/// video_ramへの描画のみで、送信はしないので失敗しない
+impl<T: Transport> DrawTarget for Matrix<T> {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if color.is_on() {
+                self.set_pixel(point.x, point.y);
+            } else {
+                self.clear_pixel(point.x, point.y);
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill_rect(
+            area.top_left.x,
+            area.top_left.y,
+            area.size.width,
+            area.size.height,
+            color.is_on(),
+        );
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let (width, height) = (self.width() as u32, self.height() as u32);
+        self.fill_rect(0, 0, width, height, color.is_on());
+        Ok(())
+    }
+}
+
+impl From<Rgb888> for Rgb {
+    fn from(color: Rgb888) -> Self {
+        Rgb::new(color.r(), color.g(), color.b())
+    }
+}
+
+impl<T: PixelTransport> OriginDimensions for RgbMatrix<T> {
+    fn size(&self) -> Size {
+        Size::new(self.width() as u32, self.height() as u32)
+    }
+}
+
+/// フレームバッファへの描画のみで、送信はしないので失敗しない
+impl<T: PixelTransport> DrawTarget for RgbMatrix<T> {
+    type Color = Rgb888;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            self.set_pixel(point.x, point.y, color.into());
+        }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill_rect(
+            area.top_left.x,
+            area.top_left.y,
+            area.size.width,
+            area.size.height,
+            color.into(),
+        );
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill(color.into());
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+//! Matrix LEDの階調表示
+//!
+//! 1ドット毎に階調(0〜levels()-1)を持つフレームバッファを、
+//! ビット毎のサブフレーム(ビットプレーン)に分けて、Matrix LEDへ時分割で表示する。
+//! (Bit Angle Modulation)
+//!
+//! ビットbのプレーンは、2^b スロットの間表示する。
+//! refresh()を一定周期(タイマー割込み等)で呼び出すと、1スロット進む。
+//! 1周期は 2^bits-1 スロットなので、ちらつかないように十分速い周期で呼ぶこと。
+//!   例) 4bit(16階調)で1周期100Hz以上 → refresh()は1.5kHz以上
+//!
+//! ビットプレーンは、Matrixのvideo_ramに描いて送るので、変化した行のみが送られる。
+
+use crate::matrix_led::{Matrix, Result, MAX_MODULES};
+use crate::transport::Transport;
+
+/// 階調の最大ビット数(16階調)
+pub const MAX_GRAY_BITS: u32 = 4;
+
+/// 階調用フレームバッファのドット数
+const GRAY_SIZE: usize = MAX_MODULES * 8 * 8;
+
+/// 階調表示のMatrix LED
+pub struct GrayMatrix<T: Transport> {
+    led: Matrix<T>,
+    buff: [u8; GRAY_SIZE], // 各ドットの階調(左上から行毎)
+    bits: u32,             // 階調のビット数
+    slot: u32,             // 現在のスロット
+    plane: Option<u32>,    // 表示中のビットプレーン
+}
+
+impl<T: Transport> GrayMatrix<T> {
+    /// 初期化済みのMatrixから、bitsビット(2^bits階調)の階調表示を作る
+    ///
+    /// # Panics
+    /// bitsが0、もしくはMAX_GRAY_BITSを超える場合
+    pub fn new(led: Matrix<T>, bits: u32) -> Self {
+        assert!(bits > 0 && bits <= MAX_GRAY_BITS);
+        GrayMatrix {
+            led,
+            buff: [0; GRAY_SIZE],
+            bits,
+            slot: 0,
+            plane: None,
+        }
+    }
+
+    /// Matrix LEDへの参照
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.led
+    }
+
+    /// Matrix LEDへの可変参照
+    /// 輝度設定等は、こちらから直接行う。
+    /// video_ramへ描いた内容は、次のrefresh()で上書きされる。
+    pub fn matrix_mut(&mut self) -> &mut Matrix<T> {
+        &mut self.led
+    }
+
+    /// Matrixを返す
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.led
+    }
+
+    /// 階調数
+    pub fn levels(&self) -> u8 {
+        1 << self.bits
+    }
+
+    /// 最大の階調(全点灯)
+    pub fn max_level(&self) -> u8 {
+        self.levels() - 1
+    }
+
+    /// 横ドット数
+    pub fn width(&self) -> usize {
+        self.led.width()
+    }
+
+    /// 縦ドット数
+    pub fn height(&self) -> usize {
+        self.led.height()
+    }
+
+    /// 全ドットを階調0にする
+    pub fn clear(&mut self) {
+        for dot in &mut self.buff[..] {
+            *dot = 0;
+        }
+    }
+
+    /// 指定ドットの階調を設定する。範囲外は無視
+    /// 最大の階調を超える値は、最大の階調とする。
+    pub fn set_level(&mut self, x: i32, y: i32, level: u8) {
+        let level = level.min(self.max_level());
+        if let Some(i) = self.index(x, y) {
+            self.buff[i] = level;
+        }
+    }
+
+    /// 指定ドットの階調。範囲外は0
+    pub fn level(&self, x: i32, y: i32) -> u8 {
+        match self.index(x, y) {
+            Some(i) => self.buff[i],
+            None => 0,
+        }
+    }
+
+    /// 指定の矩形を、指定の階調で塗りつぶす
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: u32, height: u32, level: u8) {
+        let x1 = (x as i64 + width as i64).min(self.width() as i64) as i32;
+        let y1 = (y as i64 + height as i64).min(self.height() as i64) as i32;
+        for py in y.max(0)..y1 {
+            for px in x.max(0)..x1 {
+                self.set_level(px, py, level);
+            }
+        }
+    }
+
+    /// 階調のビットマップを描く
+    ///
+    /// bitmapは1ドット1バイトで、左上から行毎に並べたもの。(幅width)
+    /// 範囲外にはみ出した部分は無視する。
+    pub fn draw_gray_bitmap(&mut self, px: i32, py: i32, width: u32, bitmap: &[u8]) {
+        if width == 0 {
+            return;
+        }
+        for (i, level) in bitmap.iter().enumerate() {
+            let (dx, dy) = (i as u32 % width, i as u32 / width);
+            self.set_level(px + dx as i32, py + dy as i32, *level);
+        }
+    }
+
+    /// 1スロット進め、表示するビットプレーンが変われば送る
+    ///
+    /// 一定周期で呼び出すこと。
+    /// 転送中の場合は、転送完了後に反映される。
+    pub fn refresh(&mut self) -> Result<()> {
+        let plane = 31 - (self.slot + 1).leading_zeros();
+        self.slot = (self.slot + 1) % ((1 << self.bits) - 1);
+        if self.plane == Some(plane) && self.bits > 1 {
+            return Ok(());
+        }
+        self.draw_plane(plane);
+        self.plane = Some(plane);
+        self.led.present()
+    }
+
+    /// 指定のビットプレーンをvideo_ramに描く
+    fn draw_plane(&mut self, plane: u32) {
+        let (width, height) = (self.width() as i32, self.height() as i32);
+        for y in 0..height {
+            for x in 0..width {
+                if (self.buff[(y * width + x) as usize] >> plane) & 1 != 0 {
+                    self.led.set_pixel(x, y);
+                } else {
+                    self.led.clear_pixel(x, y);
+                }
+            }
+        }
+    }
+
+    /// 座標からバッファのindexを求める
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width() || y as usize >= self.height() {
+            return None;
+        }
+        Some(y as usize * self.width() + x as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::RecordingTransport;
+
+    /// 4モジュールのチェーンで、bitsビットの階調表示
+    fn gray(bits: u32) -> GrayMatrix<RecordingTransport> {
+        GrayMatrix::new(Matrix::new(RecordingTransport::new()), bits)
+    }
+
+    /// 送信したパケットの数
+    fn sent(g: &GrayMatrix<RecordingTransport>) -> usize {
+        g.matrix().transport().packets().count()
+    }
+
+    #[test]
+    fn slots_per_plane() {
+        let mut g = gray(4);
+        // ドットbは、プレーンbでのみ点灯する
+        for b in 0..4 {
+            g.set_level(b, 0, 1 << b);
+        }
+        for _ in 0..2 {
+            let mut slots = [0; 4];
+            for _ in 0..15 {
+                g.refresh().unwrap();
+                let lit: Vec<_> = (0..4)
+                    .filter(|&b| g.matrix().video_ram().get(b, 0))
+                    .collect();
+                assert_eq!(lit.len(), 1);
+                slots[lit[0] as usize] += 1;
+            }
+            assert_eq!(slots, [1, 2, 4, 8]);
+        }
+    }
+
+    #[test]
+    fn changed_planes_only() {
+        let mut g = gray(4);
+        // プレーン0と1は同じ内容。プレーン2と3は、2行目のみ異なる
+        g.fill_rect(0, 0, 32, 8, 0b0011);
+        g.set_level(5, 1, 0b1011);
+        g.refresh().unwrap();
+        let start = sent(&g);
+        let mut per_slot = Vec::new();
+        for _ in 0..15 {
+            let before = sent(&g);
+            g.refresh().unwrap();
+            per_slot.push(sent(&g) - before);
+        }
+        // スロット1,3,7と次の周期のスロット0でプレーンが変わり、変化した行のみを送る
+        let expected = [0, 0, 8, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 8];
+        assert_eq!(per_slot, expected);
+        assert_eq!(sent(&g) - start, 17);
+    }
+}
@@ -0,0 +1,352 @@
+//! HT16K33によるLEDマトリクスの制御
+//!  8*8、または16*8のHT16K33バックパックを、I2Cのアドレス順に左から並べたもの
+//!
+//! 表示RAMは、COM(行)毎に2バイト。行yのデータは、アドレス2y(左8ドット)と2y+1(右8ドット)
+//! 各バイトは、ROW0(D0)を左端とする。(8*8では、アドレス2y+1は使用しない)
+//!
+//! 輝度は、HT16K33の調光(16段階)をそのまま使う。
+//! 点滅は、HT16K33のハードウェア点滅を使うので、present()の呼び出しは不要。
+//!
+//! present()では、変化のあったデバイスの表示RAM全体と、変化のあった設定のみを送る。
+
+use crate::matrix_led::{Result, MAX_BRIGHTNESS};
+use crate::panel::LedPanel;
+use crate::transport::I2cTransport;
+use crate::video_ram::VideoRam;
+
+/// HT16K33の先頭のI2Cアドレス(A0〜A2の設定で +0〜+7)
+pub const HT16K33_ADDRESS: u8 = 0x70;
+
+/// 並べられるデバイスの最大数(I2Cアドレスの数)
+pub const MAX_DEVICES: usize = 8;
+
+/// HT16K33 コマンド
+const CMD_DISPLAY_DATA: u8 = 0x00; // 表示RAMのアドレス0から書き込む
+const CMD_SYSTEM_SETUP: u8 = 0x20; // D0: 発振器ON
+const CMD_DISPLAY_SETUP: u8 = 0x80; // D2-D1: 点滅 D0: 表示ON
+const CMD_DIMMING: u8 = 0xE0; // D3-D0: 輝度
+
+/// 表示RAMのバイト数(16行分)
+const DISPLAY_RAM_SIZE: usize = 16;
+
+/// 輝度の初期値
+const DEFAULT_BRIGHTNESS: u8 = 0x02;
+
+/// バックパックの大きさ
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Ht16k33Size {
+    /// 横8*縦8ドット
+    Size8x8,
+    /// 横16*縦8ドット
+    Size16x8,
+}
+
+impl Ht16k33Size {
+    /// 1行のバイト数
+    fn stride(self) -> usize {
+        match self {
+            Ht16k33Size::Size8x8 => 1,
+            Ht16k33Size::Size16x8 => 2,
+        }
+    }
+}
+
+/// ハードウェア点滅
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Blink {
+    /// 点滅しない
+    Off,
+    /// 2Hz
+    Hz2,
+    /// 1Hz
+    Hz1,
+    /// 0.5Hz
+    HzHalf,
+}
+
+impl Blink {
+    /// 表示設定コマンドの値(表示ON)
+    fn command(self) -> u8 {
+        let blink = match self {
+            Blink::Off => 0b00,
+            Blink::Hz2 => 0b01,
+            Blink::Hz1 => 0b10,
+            Blink::HzHalf => 0b11,
+        };
+        CMD_DISPLAY_SETUP | (blink << 1) | 0x01
+    }
+}
+
+/// HT16K33のLEDマトリクス
+pub struct Ht16k33<T: I2cTransport> {
+    transport: T,
+    size: Ht16k33Size,
+    addresses: [u8; MAX_DEVICES], // 左から順のI2Cアドレス
+    devices: usize,
+    video_ram: VideoRam,
+    brightness: u8,
+    blink: Blink,
+    config_dirty: bool, // 輝度・点滅を送る必要があるか
+}
+
+impl<T: I2cTransport> Ht16k33<T> {
+    /// addressesのデバイスを左から並べたものとして初期化する
+    ///
+    /// # Panics
+    /// デバイス数が0、もしくはMAX_DEVICESを超える場合
+    pub fn new(transport: T, size: Ht16k33Size, addresses: &[u8]) -> Ht16k33<T> {
+        assert!(!addresses.is_empty() && addresses.len() <= MAX_DEVICES);
+        let mut list = [HT16K33_ADDRESS; MAX_DEVICES];
+        list[0..addresses.len()].copy_from_slice(addresses);
+        let mut led = Ht16k33 {
+            transport,
+            size,
+            addresses: list,
+            devices: addresses.len(),
+            video_ram: VideoRam::new(addresses.len() * size.stride() * 8, 8),
+            brightness: DEFAULT_BRIGHTNESS,
+            blink: Blink::Off,
+            config_dirty: true,
+        };
+        led.init_ht16k33();
+        led
+    }
+
+    /// 送信路への参照
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// バックパックの大きさ
+    pub fn size(&self) -> Ht16k33Size {
+        self.size
+    }
+
+    /// 描画用のVideo RAM
+    pub fn video_ram(&self) -> &VideoRam {
+        &self.video_ram
+    }
+
+    /// 描画用のVideo RAM(可変)
+    pub fn video_ram_mut(&mut self) -> &mut VideoRam {
+        &mut self.video_ram
+    }
+
+    /// 全デバイスの輝度を設定する(0〜MAX_BRIGHTNESS)
+    ///
+    /// 転送中の場合は、転送完了後に反映される。
+    pub fn set_brightness(&mut self, level: u8) -> Result<()> {
+        self.brightness = level.min(MAX_BRIGHTNESS);
+        self.config_dirty = true;
+        self.present()
+    }
+
+    /// 輝度
+    pub fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    /// 全デバイスのハードウェア点滅を設定する
+    ///
+    /// 転送中の場合は、転送完了後に反映される。
+    pub fn set_blink(&mut self, blink: Blink) -> Result<()> {
+        self.blink = blink;
+        self.config_dirty = true;
+        self.present()
+    }
+
+    /// 点滅の設定
+    pub fn blink(&self) -> Blink {
+        self.blink
+    }
+
+    /// video_ramの内容と、変更された設定を表示する
+    ///
+    /// 転送中であれば、転送完了後に表示される。(完了を待たずに戻る)
+    /// 転送待ちのフレームは破棄されるので、その分も含めて送り直す。
+    pub fn present(&mut self) -> Result<()> {
+        if self.transport.is_pending() {
+            self.video_ram.invalidate();
+            self.config_dirty = true;
+        }
+        if let Err(e) = self.transport.begin() {
+            self.video_ram.invalidate();
+            self.config_dirty = true;
+            return Err(e);
+        }
+        if self.config_dirty {
+            let dimming = CMD_DIMMING | self.brightness;
+            let setup = self.blink.command();
+            for i in 0..self.devices {
+                self.transport.write(self.addresses[i], &[dimming])?;
+                self.transport.write(self.addresses[i], &[setup])?;
+            }
+        }
+        for i in 0..self.devices {
+            if self.is_device_dirty(i) {
+                self.send_display_ram(i)?;
+            }
+        }
+        self.transport.flush()?;
+        self.video_ram.clear_dirty();
+        self.config_dirty = false;
+        Ok(())
+    }
+
+    /// 指定デバイスの範囲に、変化したバイトがあるか
+    fn is_device_dirty(&self, device: usize) -> bool {
+        let stride = self.size.stride();
+        (0..8).any(|y| (0..stride).any(|c| self.video_ram.is_dirty(device * stride + c, y)))
+    }
+
+    /// 指定デバイスの表示RAM全体を、1トランザクションで送る
+    fn send_display_ram(&mut self, device: usize) -> Result<()> {
+        let stride = self.size.stride();
+        let mut dat = [0u8; DISPLAY_RAM_SIZE + 1];
+        dat[0] = CMD_DISPLAY_DATA;
+        for y in 0..8 {
+            for c in 0..stride {
+                // video_ramは左端がMSB、HT16K33は左端(ROW0)がD0
+                let byte = self.video_ram.byte(device * stride + c, y);
+                dat[1 + y * 2 + c] = byte.reverse_bits();
+            }
+        }
+        self.transport.write(self.addresses[device], &dat)
+    }
+
+    /// HT16K33 初期化
+    fn init_ht16k33(&mut self) {
+        while self.transport.begin().is_err() {}
+        for i in 0..self.devices {
+            self.transport
+                .write(self.addresses[i], &[CMD_SYSTEM_SETUP | 0x01]) // 発振器ON
+                .unwrap();
+        }
+        self.transport.flush().unwrap();
+        while self.present().is_err() {}
+    }
+}
+
+impl<T: I2cTransport> LedPanel for Ht16k33<T> {
+    fn video_ram(&self) -> &VideoRam {
+        &self.video_ram
+    }
+
+    fn video_ram_mut(&mut self) -> &mut VideoRam {
+        &mut self.video_ram
+    }
+
+    fn present(&mut self) -> Result<()> {
+        Ht16k33::present(self)
+    }
+
+    fn set_brightness(&mut self, level: u8) -> Result<()> {
+        Ht16k33::set_brightness(self, level)
+    }
+
+    fn is_busy(&self) -> bool {
+        self.transport.is_busy()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::RecordingI2c;
+
+    /// 送信したトランザクションの一覧
+    fn transactions(led: &Ht16k33<RecordingI2c>) -> Vec<(u8, Vec<u8>)> {
+        led.transport()
+            .transactions()
+            .map(|(address, bytes)| (address, bytes.to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn init_sequence() {
+        let led = Ht16k33::new(RecordingI2c::new(), Ht16k33Size::Size16x8, &[0x70, 0x72]);
+        let sent = transactions(&led);
+        assert_eq!(sent.len(), 2 + 4 + 2);
+        assert_eq!(sent[0], (0x70, vec![0x21]));
+        assert_eq!(sent[1], (0x72, vec![0x21]));
+        assert_eq!(sent[2], (0x70, vec![0xE2]));
+        assert_eq!(sent[3], (0x70, vec![0x81]));
+        assert_eq!(sent[5], (0x72, vec![0x81]));
+        assert_eq!(sent[7], (0x72, vec![0x00; 17]));
+        assert_eq!(led.transport().flush_count(), 2);
+    }
+
+    #[test]
+    fn display_ram_layout() {
+        let mut led = Ht16k33::new(RecordingI2c::new(), Ht16k33Size::Size16x8, &[0x70, 0x72]);
+        led.transport.clear();
+        // 左端(ROW0)がD0。行yの右8ドットは、アドレス2y+1
+        led.video_ram_mut().put(0, 0, true);
+        led.video_ram_mut().put(9, 3, true);
+        led.present().unwrap();
+        let mut ram = vec![0x00; 17];
+        ram[1] = 0x01;
+        ram[1 + 3 * 2 + 1] = 0x02;
+        // 変化の無いデバイスは送らない
+        assert_eq!(transactions(&led), [(0x70, ram)]);
+
+        led.transport.clear();
+        led.video_ram_mut().put(31, 7, true);
+        led.present().unwrap();
+        let mut ram = vec![0x00; 17];
+        ram[1 + 7 * 2 + 1] = 0x80;
+        assert_eq!(transactions(&led), [(0x72, ram)]);
+
+        led.transport.clear();
+        led.present().unwrap();
+        assert!(transactions(&led).is_empty());
+        assert_eq!(led.transport().flush_count(), 1);
+    }
+
+    #[test]
+    fn display_ram_8x8() {
+        let mut led = Ht16k33::new(RecordingI2c::new(), Ht16k33Size::Size8x8, &[0x71]);
+        assert_eq!(led.video_ram().width(), 8);
+        led.transport.clear();
+        led.video_ram_mut().put(7, 2, true);
+        led.video_ram_mut().put(1, 5, true);
+        led.present().unwrap();
+        // アドレス2y+1は使用しない
+        let mut ram = vec![0x00; 17];
+        ram[1 + 2 * 2] = 0x80;
+        ram[1 + 5 * 2] = 0x02;
+        assert_eq!(transactions(&led), [(0x71, ram)]);
+    }
+
+    #[test]
+    fn dimming_and_blink() {
+        let mut led = Ht16k33::new(RecordingI2c::new(), Ht16k33Size::Size8x8, &[0x70, 0x71]);
+        led.transport.clear();
+        led.set_brightness(0x20).unwrap();
+        assert_eq!(led.brightness(), MAX_BRIGHTNESS);
+        assert_eq!(
+            transactions(&led),
+            [
+                (0x70, vec![0xEF]),
+                (0x70, vec![0x81]),
+                (0x71, vec![0xEF]),
+                (0x71, vec![0x81]),
+            ]
+        );
+        let blinks = [
+            (Blink::Off, 0x81),
+            (Blink::Hz2, 0x83),
+            (Blink::Hz1, 0x85),
+            (Blink::HzHalf, 0x87),
+        ];
+        for &(blink, command) in &blinks {
+            led.transport.clear();
+            led.set_blink(blink).unwrap();
+            assert_eq!(led.blink(), blink);
+            let sent = transactions(&led);
+            assert_eq!(sent.len(), 4);
+            assert_eq!(sent[1], (0x70, vec![command]));
+            assert_eq!(sent[3], (0x71, vec![command]));
+        }
+    }
+}
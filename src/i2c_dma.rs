@@ -0,0 +1,500 @@
+//! I2C1 + DMA1 による I2C LEDドライバへの送信路
+//!  SCL:PB6 SDA:PB7 (オープンドレイン 400kHz)
+//!
+//! 1トランザクションは、次の順に割込みで進める。
+//!   START → (SB) アドレス送信 → (ADDR) データをDMA1 Stream6でI2C1_DRへ
+//!   → (BTF かつ DMA終了) STOP → 次のトランザクションのSTART
+//! 1フレームの全トランザクションを送り終えると、保留中のフレームの送信を開始する。
+//!
+//! DMAバッファは2面持ち、一方を転送中に、もう一方へ次のフレームを組み立てる。
+//! 保留中のフレームは、次のbegin()で破棄され、新しいフレームに置き換わる。
+//!
+//! NACK・バスエラー・DMAのエラーは割込みで記録し、フレームの残りを中止する。
+//! STOPの送信が一定時間内に完了しない場合も、バスエラーとして記録する。
+//! 記録したエラーは、次のbegin()で一度だけ返す。
+
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use stm32f4::stm32f401;
+use stm32f4::stm32f401::interrupt;
+
+use crate::matrix_led::{Error, Result};
+use crate::transport::I2cTransport;
+
+/// I2C1の基準クロック(APB1 48MHz)
+const PCLK1_MHZ: u8 = 48;
+/// SCL 400kHz (Fastモード Tlow/Thigh=2) 48MHz/(3*40)
+const CCR_FAST_400K: u16 = 40;
+/// SCLの立ち上がり時間上限 300ns*48MHz + 1
+const TRISE_FAST: u8 = 15;
+/// STOPの送信完了を待つ上限(読み出し回数)。400kHzで数μsのところに、十分な余裕を取る
+const STOP_WAIT_LIMIT: u32 = 10_000;
+
+/// I2C1 + DMA1による送信路
+pub struct I2cDma<'a> {
+    device: &'a stm32f401::Peripherals,
+    writing: Option<usize>, // 組み立て中のDMAバッファ番号
+}
+
+impl<'a> I2cDma<'a> {
+    /// 初期化する
+    ///
+    /// # Panics
+    /// 既に他のI2cDmaが作られている場合
+    pub fn new(device: &'a stm32f401::Peripherals) -> I2cDma<'a> {
+        let claimed = free(|cs| {
+            let mut state = I2C_STATE.borrow(cs).borrow_mut();
+            let claimed = state.claimed;
+            state.claimed = true;
+            claimed
+        });
+        assert!(!claimed);
+
+        let i2c_dma = I2cDma {
+            device,
+            writing: None,
+        };
+        i2c_dma.gpio_setup();
+        i2c_dma.i2c_setup();
+        i2c_dma.dma_setup();
+        i2c_dma
+    }
+
+    /// 指定のトランザクションの送信を開始する
+    ///   アドレスの送信以降は、割込みで進める
+    fn start_transaction(device: &stm32f401::Peripherals, buff: &'static I2cBuff, index: usize) {
+        let (_, start, len) = buff.transaction(index);
+        let st = &device.DMA1.st[6];
+        st.m0ar
+            .write(|w| w.m0a().bits(buff.bytes_ptr() + start as u32));
+        st.ndtr.write(|w| w.ndt().bits(len as u16));
+        Self::clear_dma_flags(device);
+        st.cr.modify(|_, w| w.en().enabled());
+        device.I2C1.cr1.modify(|_, w| w.start().set_bit());
+    }
+
+    /// STOPを送り、DMAストリームを止める
+    ///   STOPの送信完了まで、次のSTARTは掛けられない(数μs)
+    ///   SCLが押さえられたままなどで完了しなければ、Error::I2cBusError
+    fn stop_transaction(device: &stm32f401::Peripherals) -> Result<()> {
+        let i2c = &device.I2C1;
+        i2c.cr1.modify(|_, w| w.stop().set_bit());
+        device.DMA1.st[6].cr.modify(|_, w| w.en().disabled());
+        Self::clear_dma_flags(device);
+        for _ in 0..STOP_WAIT_LIMIT {
+            if i2c.cr1.read().stop().bit_is_clear() {
+                return Ok(());
+            }
+        }
+        Err(Error::I2cBusError)
+    }
+
+    /// トランザクションの送信終了時の処理
+    ///   次のトランザクション、または保留中のフレームの送信を開始する
+    fn on_transaction_end(device: &stm32f401::Peripherals) {
+        if let Err(error) = Self::stop_transaction(device) {
+            Self::abort_frame(device, error);
+            return;
+        }
+        let next = free(|cs| {
+            let mut state = I2C_STATE.borrow(cs).borrow_mut();
+            let index = state.active?;
+            state.current += 1;
+            if state.current < I2C_BUFF[index].transactions() {
+                return Some((index, state.current));
+            }
+            state.next_frame().map(|index| (index, 0))
+        });
+        if let Some((index, current)) = next {
+            Self::start_transaction(device, &I2C_BUFF[index], current);
+        }
+    }
+
+    /// エラー発生時の処理
+    ///   エラーを記録し、フレームの残りを中止して、保留中のフレームの送信を開始する
+    fn on_error(device: &stm32f401::Peripherals, error: Error) {
+        // アービトレーション負けでは、バスは解放済み
+        if error != Error::I2cBusError || device.I2C1.sr2.read().msl().bit_is_set() {
+            // STOPが完了しなくても、記録するのは元のエラー
+            let _ = Self::stop_transaction(device);
+        } else {
+            device.DMA1.st[6].cr.modify(|_, w| w.en().disabled());
+            Self::clear_dma_flags(device);
+        }
+        Self::abort_frame(device, error);
+    }
+
+    /// エラーを記録してフレームの残りを中止し、保留中のフレームの送信を開始する
+    fn abort_frame(device: &stm32f401::Peripherals, error: Error) {
+        let next = free(|cs| {
+            let mut state = I2C_STATE.borrow(cs).borrow_mut();
+            state.error = Some(error);
+            state.next_frame()
+        });
+        if let Some(index) = next {
+            Self::start_transaction(device, &I2C_BUFF[index], 0);
+        }
+    }
+
+    /// I2C1のイベント割込み処理
+    fn on_event(device: &stm32f401::Peripherals) {
+        let i2c = &device.I2C1;
+        let sr1 = i2c.sr1.read();
+        if sr1.sb().bit_is_set() {
+            // START送信済み。アドレス(書き込み)を送る
+            let address = free(|cs| {
+                let state = I2C_STATE.borrow(cs).borrow();
+                state
+                    .active
+                    .map(|index| I2C_BUFF[index].transaction(state.current).0)
+            });
+            match address {
+                Some(address) => i2c.dr.write(|w| unsafe { w.bits((address << 1) as u32) }),
+                None => i2c.cr1.modify(|_, w| w.stop().set_bit()),
+            }
+        } else if sr1.addr().bit_is_set() {
+            // SR1→SR2の読み出しでADDRをクリアすると、DMAでのデータ送信が始まる
+            let _ = i2c.sr2.read();
+        } else if sr1.btf().bit_is_set() && device.DMA1.st[6].ndtr.read().ndt().bits() == 0 {
+            // 最後のバイトの送信完了
+            Self::on_transaction_end(device);
+        }
+    }
+
+    /// I2C1のエラー割込み処理
+    fn on_bus_error(device: &stm32f401::Peripherals) {
+        let i2c = &device.I2C1;
+        let sr1 = i2c.sr1.read();
+        let error = if sr1.af().bit_is_set() {
+            Error::I2cNack
+        } else {
+            Error::I2cBusError
+        };
+        i2c.sr1.modify(|_, w| {
+            w.af().clear_bit();
+            w.berr().clear_bit();
+            w.arlo().clear_bit();
+            w.ovr().clear_bit()
+        });
+        Self::on_error(device, error);
+    }
+
+    /// DMAストリームのエラーフラグを調べる
+    fn dma_error(device: &stm32f401::Peripherals) -> Option<Error> {
+        let hisr = device.DMA1.hisr.read();
+        if hisr.teif6().is_error() {
+            Some(Error::TransferError)
+        } else if hisr.dmeif6().is_error() {
+            Some(Error::FifoError)
+        } else {
+            None
+        }
+    }
+
+    /// DMAストリームの完了・エラーフラグをクリアする
+    fn clear_dma_flags(device: &stm32f401::Peripherals) {
+        device.DMA1.hifcr.write(|w| {
+            w.ctcif6().clear();
+            w.chtif6().clear();
+            w.cteif6().clear();
+            w.cdmeif6().clear();
+            w.cfeif6().clear()
+        });
+    }
+
+    /// DMAストリーム・I2Cが停止しているか
+    fn is_inactive(device: &stm32f401::Peripherals) -> Result<()> {
+        if device.DMA1.st[6].cr.read().en().is_enabled() {
+            Err(Error::DmaStreamActive)
+        } else if device.I2C1.sr2.read().busy().bit_is_set() {
+            Err(Error::Busy)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// gpioのセットアップ
+    ///   PB6(SCL), PB7(SDA) 代替機能4 オープンドレイン プルアップ
+    fn gpio_setup(&self) {
+        self.device.RCC.ahb1enr.modify(|_, w| w.gpioben().enabled());
+        let gpiob = &self.device.GPIOB;
+        gpiob.afrl.modify(|_, w| w.afrl6().af4().afrl7().af4());
+        gpiob
+            .otyper
+            .modify(|_, w| w.ot6().open_drain().ot7().open_drain());
+        gpiob
+            .pupdr
+            .modify(|_, w| w.pupdr6().pull_up().pupdr7().pull_up());
+        gpiob
+            .ospeedr
+            .modify(|_, w| w.ospeedr6().high_speed().ospeedr7().high_speed());
+        gpiob
+            .moder
+            .modify(|_, w| w.moder6().alternate().moder7().alternate());
+    }
+
+    /// I2C1のセットアップ
+    ///   マスター送信のみ。データの書き込みはDMAで行う
+    fn i2c_setup(&self) {
+        self.device.RCC.apb1enr.modify(|_, w| w.i2c1en().enabled());
+        let i2c = &self.device.I2C1;
+        i2c.cr1.modify(|_, w| w.pe().clear_bit());
+        i2c.cr1.modify(|_, w| w.swrst().set_bit());
+        i2c.cr1.modify(|_, w| w.swrst().clear_bit());
+        i2c.cr2.modify(|_, w| unsafe {
+            w.freq().bits(PCLK1_MHZ);
+            w.iterren().set_bit();
+            w.itevten().set_bit();
+            w.itbufen().clear_bit();
+            w.dmaen().set_bit()
+        });
+        i2c.ccr.write(|w| unsafe {
+            w.f_s().set_bit();
+            w.duty().clear_bit();
+            w.ccr().bits(CCR_FAST_400K)
+        });
+        i2c.trise.write(|w| w.trise().bits(TRISE_FAST));
+        i2c.cr1.modify(|_, w| w.pe().set_bit());
+
+        unsafe {
+            cortex_m::peripheral::NVIC::unmask(stm32f401::interrupt::I2C1_EV);
+            cortex_m::peripheral::NVIC::unmask(stm32f401::interrupt::I2C1_ER);
+        }
+    }
+
+    /// DMAのセットアップ
+    ///   DMA1 Stream6 チャンネル1(I2C1_TX)
+    fn dma_setup(&self) {
+        self.device.RCC.ahb1enr.modify(|_, w| w.dma1en().enabled());
+        let st = &self.device.DMA1.st[6];
+        st.cr.modify(|_, w| {
+            w.chsel().bits(1u8);
+            w.mburst().single();
+            w.pburst().single();
+            w.ct().memory0();
+            w.dbm().disabled();
+            w.pl().high();
+            w.pincos().psize();
+            w.msize().bits8();
+            w.psize().bits8();
+            w.minc().incremented();
+            w.pinc().fixed();
+            w.circ().disabled();
+            w.dir().memory_to_peripheral();
+            w.tcie().disabled();
+            w.htie().disabled();
+            w.teie().enabled();
+            w.dmeie().enabled()
+        });
+        st.fcr.modify(|_, w| {
+            w.feie().disabled();
+            w.dmdis().disabled();
+            w.fth().half()
+        });
+        st.par
+            .write(|w| w.pa().bits(&self.device.I2C1.dr as *const _ as u32));
+
+        unsafe {
+            cortex_m::peripheral::NVIC::unmask(stm32f401::interrupt::DMA1_STREAM6);
+        }
+    }
+
+    /// 組み立て中のDMAバッファ
+    fn writing_buff(&self) -> Result<&'static I2cBuff> {
+        let index = self.writing.ok_or(Error::NotInitialised)?;
+        Ok(&I2C_BUFF[index])
+    }
+}
+
+impl I2cTransport for I2cDma<'_> {
+    /// 転送中でないDMAバッファを確保する
+    /// 保留中のフレームがあれば、破棄してそのバッファを使う
+    /// 前回までの転送でエラーがあれば、そのエラーを返す
+    fn begin(&mut self) -> Result<()> {
+        let index = free(|cs| {
+            let mut state = I2C_STATE.borrow(cs).borrow_mut();
+            if let Some(error) = state.error.take() {
+                return Err(error);
+            }
+            state.pending = None;
+            Ok(match state.active {
+                Some(0) => 1,
+                _ => 0,
+            })
+        })?;
+        I2C_BUFF[index].clear_buff();
+        self.writing = Some(index);
+        Ok(())
+    }
+
+    /// 空のバイト列は、送るものが無いので積まない
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<()> {
+        if address > 0x7F {
+            return Err(Error::OutOfRange);
+        }
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        self.writing_buff()?.add_transaction(address, bytes)
+    }
+
+    /// 転送中でなければ送信を開始し、転送中であれば保留する
+    fn flush(&mut self) -> Result<()> {
+        let index = self.writing.take().ok_or(Error::NotInitialised)?;
+        if I2C_BUFF[index].transactions() == 0 {
+            return Ok(()); // 送るものが無い
+        }
+        let start = free(|cs| {
+            let mut state = I2C_STATE.borrow(cs).borrow_mut();
+            if state.active.is_none() {
+                state.active = Some(index);
+                state.current = 0;
+                true
+            } else {
+                state.pending = Some(index);
+                false
+            }
+        });
+        if start {
+            if let Err(e) = Self::is_inactive(self.device) {
+                free(|cs| I2C_STATE.borrow(cs).borrow_mut().active = None);
+                return Err(e);
+            }
+            Self::start_transaction(self.device, &I2C_BUFF[index], 0);
+        }
+        Ok(())
+    }
+
+    fn is_busy(&self) -> bool {
+        free(|cs| I2C_STATE.borrow(cs).borrow().active.is_some())
+    }
+
+    fn is_pending(&self) -> bool {
+        free(|cs| I2C_STATE.borrow(cs).borrow().pending.is_some())
+    }
+}
+
+/// I2C1 イベント割込み関数
+///   START・アドレス送信・最後のバイトの送信完了時に呼ばれる
+#[interrupt]
+fn I2C1_EV() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    I2cDma::on_event(&device);
+}
+
+/// I2C1 エラー割込み関数
+///   NACK・バスエラー・アービトレーション負けで呼ばれる
+#[interrupt]
+fn I2C1_ER() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    I2cDma::on_bus_error(&device);
+}
+
+/// DMA1 Stream6(I2C1送信) 割込み関数
+///   エラー発生時のみ呼ばれる
+#[interrupt]
+fn DMA1_STREAM6() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    if let Some(error) = I2cDma::dma_error(&device) {
+        I2cDma::on_error(&device, error);
+    }
+}
+
+/// DMAバッファの使用状況
+struct I2cState {
+    active: Option<usize>,  // 転送中のバッファ番号
+    pending: Option<usize>, // 転送待ちのバッファ番号
+    current: usize,         // 転送中のトランザクション番号
+    error: Option<Error>,   // 未通知のエラー
+    claimed: bool,          // I2cDmaで使用中か
+}
+
+impl I2cState {
+    /// 転送の終わったフレームを、保留中のフレームに切り替える
+    fn next_frame(&mut self) -> Option<usize> {
+        self.active = self.pending.take();
+        self.current = 0;
+        self.active
+    }
+}
+
+static I2C_STATE: Mutex<RefCell<I2cState>> = Mutex::new(RefCell::new(I2cState {
+    active: None,
+    pending: None,
+    current: 0,
+    error: None,
+    claimed: false,
+}));
+
+/// DMAバッファに積めるバイト数
+const I2C_BYTES: usize = 256;
+/// DMAバッファに積めるトランザクション数
+const I2C_TRANSACTIONS: usize = 32;
+
+/// DMAバッファ領域(2面)
+/// 　グローバル変数・i2c_dmaモジュール以外での操作禁止
+///   I2C_STATEにて転送中・転送待ちとなっていないバッファのみ操作可能
+static I2C_BUFF: [I2cBuff; 2] = [I2C_BUFF_INIT, I2C_BUFF_INIT];
+
+use core::cell::UnsafeCell;
+struct I2cBuff {
+    bytes: UnsafeCell<[u8; I2C_BYTES]>, // I2C1_DRへ送るバイト
+    transactions: UnsafeCell<[(u8, usize, usize); I2C_TRANSACTIONS]>, // (アドレス, 先頭, 長さ)
+    byte_count: UnsafeCell<usize>,
+    transaction_count: UnsafeCell<usize>,
+}
+
+const I2C_BUFF_INIT: I2cBuff = I2cBuff {
+    bytes: UnsafeCell::new([0u8; I2C_BYTES]),
+    transactions: UnsafeCell::new([(0, 0, 0); I2C_TRANSACTIONS]),
+    byte_count: UnsafeCell::new(0),
+    transaction_count: UnsafeCell::new(0),
+};
+
+unsafe impl Sync for I2cBuff {}
+
+impl I2cBuff {
+    pub fn clear_buff(&self) {
+        unsafe {
+            *self.byte_count.get() = 0;
+            *self.transaction_count.get() = 0;
+        }
+    }
+
+    pub fn add_transaction(&self, address: u8, bytes: &[u8]) -> Result<()> {
+        unsafe {
+            let start = *self.byte_count.get();
+            let index = *self.transaction_count.get();
+            if start + bytes.len() > I2C_BYTES || index >= I2C_TRANSACTIONS {
+                return Err(Error::BufferOverflow);
+            }
+            (*self.bytes.get())[start..start + bytes.len()].copy_from_slice(bytes);
+            (*self.transactions.get())[index] = (address, start, bytes.len());
+            *self.byte_count.get() += bytes.len();
+            *self.transaction_count.get() += 1;
+        }
+        Ok(())
+    }
+
+    fn transactions(&self) -> usize {
+        unsafe { *self.transaction_count.get() }
+    }
+
+    /// index番目のトランザクション(アドレス, 先頭, 長さ)
+    fn transaction(&self, index: usize) -> (u8, usize, usize) {
+        unsafe { (*self.transactions.get())[index] }
+    }
+
+    fn bytes_ptr(&self) -> u32 {
+        self.bytes.get() as u32
+    }
+}
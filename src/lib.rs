@@ -1,4 +1,27 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 // matrix ledの制御
+//
+// STM32F401の周辺回路を直接使うモジュール(spi_dma・i2c_dma・ws2812_dma)は、
+// ターゲット(target_os = "none")でのみコンパイルする。
+// それ以外は、Recording*の送信路でホスト上でもテストできる。
+//   cargo test --lib --target x86_64-unknown-linux-gnu
 pub mod display_led;
+pub mod display_rgb;
+pub mod font;
+pub mod graphics;
+pub mod grayscale;
+pub mod ht16k33;
+#[cfg(target_os = "none")]
+pub mod i2c_dma;
+mod jis0208;
 pub mod matrix_led;
+pub mod panel;
+pub mod rgb_matrix;
+pub mod seven_segment;
+pub mod snapshot;
+#[cfg(target_os = "none")]
+pub mod spi_dma;
+pub mod transport;
+pub mod video_ram;
+#[cfg(target_os = "none")]
+pub mod ws2812_dma;
@@ -18,7 +18,12 @@ use matrixled::display_led;
 use matrixled::display_led::DisplayLed;
 use matrixled::print_led;
 
-const WAIT_TIME: u16 = 1000u16 - 1;
+/// tick周期 10ms
+const TICK_TIME: u16 = 10u16 - 1;
+/// 1秒あたりのtick数
+const TICKS_PER_SEC: u32 = 100;
+/// 起動時のフェードイン時間(tick数)
+const FADE_IN_TICKS: u32 = 100;
 
 static WAKE_TIMER: WakeTimer = WAKE_TIMER_INIT;
 
@@ -35,26 +40,34 @@ fn main() -> ! {
     //device.GPIOA.bsrr.write(|w| w.bs0().set());
 
     let tim11 = &device.TIM11;
-    tim11.arr.modify(|_, w| unsafe { w.arr().bits(WAIT_TIME) });
+    tim11.arr.modify(|_, w| unsafe { w.arr().bits(TICK_TIME) });
     tim11.cr1.modify(|_, w| w.cen().enabled());
     free(|cs| WAKE_TIMER.set(cs));
 
+    led.matrix_mut().fade_in(FADE_IN_TICKS);
+
+    let mut ticks = 0;
     let mut hh = 0;
     let mut mm = 0;
     let mut ss = 0;
     loop {
         // タイマー割込み確認
         if free(|cs| WAKE_TIMER.get(cs)) {
-            print_led!(led, "{:>02}:{:>02}:{:>02}\n", hh, mm, ss);
-            ss += 1;
-            if ss > 59 {
-                ss = 0;
-                mm += 1;
-                if mm > 59 {
-                    mm = 0;
-                    hh += 1;
-                    if hh > 99 {
-                        hh = 0;
+            led.tick();
+            ticks += 1;
+            if ticks >= TICKS_PER_SEC {
+                ticks = 0;
+                print_led!(led, "{:>02}:{:>02}:{:>02}\n", hh, mm, ss);
+                ss += 1;
+                if ss > 59 {
+                    ss = 0;
+                    mm += 1;
+                    if mm > 59 {
+                        mm = 0;
+                        hh += 1;
+                        if hh > 99 {
+                            hh = 0;
+                        }
                     }
                 }
             }
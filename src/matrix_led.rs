@@ -1,39 +1,458 @@
 //! matrix_ledの制御
-//!  ledサイズ　32*8
+//!  ledサイズ　8*8のモジュールを横cols個*縦rows個並べたもの
+//!  (モジュールの総数は最大MAX_MODULES個)
+//!
+//! 描画は、常にバックバッファであるvideo_ramに対して行う。
+//! present()で、video_ramの内容を送信用のフレーム(フロントバッファ)に変換して
+//! Transportに渡し、転送の完了を待たずに戻る。
+//! 送信するのは、前回の送信以降に変化した桁(行)のみ。変化の無いチップには、
+//! No-opワードを送る。
 
-use core::cell::RefCell;
-use cortex_m::interrupt::{free, Mutex};
-use stm32f4::stm32f401;
-use stm32f4::stm32f401::interrupt;
+use core::fmt;
 
-type Result<T> = core::result::Result<T, &'static str>;
+use crate::panel::LedPanel;
+use crate::transport::Transport;
+use crate::video_ram::{RasterOp, VideoRam};
+
+/// matrix_ledのエラー
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// 転送中
+    Busy,
+    /// DMAストリームが動作中
+    DmaStreamActive,
+    /// バッファの容量不足
+    BufferOverflow,
+    /// DMAの転送エラー
+    TransferError,
+    /// DMAのFIFO(ダイレクトモード)エラー
+    FifoError,
+    /// 初期化(フレームの組み立て開始)前の操作
+    NotInitialised,
+    /// 範囲外の指定
+    OutOfRange,
+    /// I2Cの応答無し(NACK)
+    I2cNack,
+    /// I2Cのバスエラー(アービトレーション負けを含む)
+    I2cBusError,
+    /// 読み込んだデータの形式が不正
+    InvalidFormat,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match self {
+            Error::Busy => "busy",
+            Error::DmaStreamActive => "DMA stream active",
+            Error::BufferOverflow => "buffer overflow",
+            Error::TransferError => "DMA transfer error",
+            Error::FifoError => "DMA FIFO error",
+            Error::NotInitialised => "not initialised",
+            Error::OutOfRange => "out of range",
+            Error::I2cNack => "I2C no acknowledge",
+            Error::I2cBusError => "I2C bus error",
+            Error::InvalidFormat => "invalid format",
+        };
+        f.write_str(msg)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// チェーンに接続可能な8*8モジュールの最大数
+pub const MAX_MODULES: usize = 16;
+
+/// 輝度の最大値(MAX7219の輝度レジスタ下位4bit)
+pub const MAX_BRIGHTNESS: u8 = 0x0F;
+
+/// 輝度の初期値
+const DEFAULT_BRIGHTNESS: u8 = 0x02;
+
+/// MAX7219 レジスタアドレス
+const REG_INTENSITY: u16 = 0x0A00;
+const REG_SHUTDOWN: u16 = 0x0C00;
+const REG_NO_OP: u16 = 0x0000;
+
+/// 設定レジスタ(輝度・シャットダウン以外)の内容
+const CONFIG_PAT: [u16; 3] = [
+    0x0F00, // テストモード解除
+    0x0900, // BCDデコードバイパス
+    0x0B07, // スキャン桁指定 下位4bit MAX:7
+];
+
+/// 設定レジスタの再送方針
+///
+/// ノイズ等でMAX7219のレジスタが化けた場合(テストモード・シャットダウン・
+/// スキャン桁数等)に、設定を送り直して復帰させる。
+/// 再送するのは、設定値と同じ値なので、表示が乱れることは無い。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SelfHeal {
+    /// 再送しない
+    Off,
+    /// present() n回毎に再送する
+    EveryFrames(u32),
+    /// tick() n回毎に再送する
+    EveryTicks(u32),
+}
+
+/// モジュールの配線順
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Wiring {
+    /// 全ての段を左から右へ
+    Progressive,
+    /// 段毎に折り返す(偶数段は左から右、奇数段は右から左)
+    Serpentine,
+}
+
+/// 回転(時計回り)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+/// 向き(反転の後に回転する)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Orientation {
+    pub rotation: Rotation,
+    pub mirror_x: bool, // 左右反転
+    pub mirror_y: bool, // 上下反転
+}
+
+impl Orientation {
+    /// 回転・反転無し
+    pub const IDENTITY: Orientation = Orientation::new(Rotation::Deg0, false, false);
+
+    pub const fn new(rotation: Rotation, mirror_x: bool, mirror_y: bool) -> Orientation {
+        Orientation {
+            rotation,
+            mirror_x,
+            mirror_y,
+        }
+    }
+
+    /// 回転のみ
+    pub const fn rotate(rotation: Rotation) -> Orientation {
+        Self::new(rotation, false, false)
+    }
+
+    /// 回転・反転無しか
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// 縦横が入れ替わるか
+    pub fn is_transposed(&self) -> bool {
+        matches!(self.rotation, Rotation::Deg90 | Rotation::Deg270)
+    }
+
+    /// 変換前(width*height)の座標を、変換後の座標にする
+    pub fn apply(&self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        let x = if self.mirror_x { width - 1 - x } else { x };
+        let y = if self.mirror_y { height - 1 - y } else { y };
+        match self.rotation {
+            Rotation::Deg0 => (x, y),
+            Rotation::Deg90 => (height - 1 - y, x),
+            Rotation::Deg180 => (width - 1 - x, height - 1 - y),
+            Rotation::Deg270 => (y, width - 1 - x),
+        }
+    }
+
+    /// 変換後の座標を、変換前(width*height)の座標に戻す
+    pub fn invert(&self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        let (x, y) = match self.rotation {
+            Rotation::Deg0 => (x, y),
+            Rotation::Deg90 => (y, height - 1 - x),
+            Rotation::Deg180 => (width - 1 - x, height - 1 - y),
+            Rotation::Deg270 => (width - 1 - y, x),
+        };
+        let x = if self.mirror_x { width - 1 - x } else { x };
+        let y = if self.mirror_y { height - 1 - y } else { y };
+        (x, y)
+    }
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// パネル構成
+///
+/// モジュールの順番は、1パケット内の送信順で数える。
+/// 先頭のワードは、チェーンの終端(マイコンから最も遠い)モジュールに入る。
+/// 送信順の先頭が左上隅のモジュールとなる。
+///
+/// 描画は、論理座標(orientation適用前)で行う。
+///   orientation:        論理座標から、パネル上の物理的な位置への変換
+///                       (パネルを逆さまに取り付けた場合等)
+///   module_orientation: MAX7219の並び(桁レジスタが行、D7が左端)から、
+///                       モジュール上の位置への変換(FC-16等、基板による違い)
+///                       モジュール毎に、送信順で指定する。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PanelConfig {
+    pub cols: usize, // 横方向のモジュール数(物理)
+    pub rows: usize, // 縦方向のモジュール数(物理)
+    pub wiring: Wiring,
+    pub orientation: Orientation,
+    pub module_orientation: [Orientation; MAX_MODULES], // 送信順
+}
+
+impl PanelConfig {
+    pub const fn new(cols: usize, rows: usize, wiring: Wiring) -> PanelConfig {
+        PanelConfig {
+            cols,
+            rows,
+            wiring,
+            orientation: Orientation::IDENTITY,
+            module_orientation: [Orientation::IDENTITY; MAX_MODULES],
+        }
+    }
+
+    /// パネル全体の向きを指定する
+    pub const fn with_orientation(self, orientation: Orientation) -> PanelConfig {
+        PanelConfig {
+            orientation,
+            ..self
+        }
+    }
+
+    /// 全てのモジュール内の向きを、同じ向きに指定する
+    pub const fn with_module_orientation(self, orientation: Orientation) -> PanelConfig {
+        PanelConfig {
+            module_orientation: [orientation; MAX_MODULES],
+            ..self
+        }
+    }
+
+    /// 各モジュール内の向きを、送信順に指定する
+    pub const fn with_module_orientations(
+        self,
+        orientations: [Orientation; MAX_MODULES],
+    ) -> PanelConfig {
+        PanelConfig {
+            module_orientation: orientations,
+            ..self
+        }
+    }
+
+    /// 送信順index番目のモジュール内の向きを指定する
+    pub fn set_module_orientation(&mut self, index: usize, orientation: Orientation) -> Result<()> {
+        if index >= self.modules() {
+            return Err(Error::OutOfRange);
+        }
+        self.module_orientation[index] = orientation;
+        Ok(())
+    }
+
+    /// 横一列に並んだmodules個のチェーン
+    pub const fn chain(modules: usize) -> PanelConfig {
+        Self::new(modules, 1, Wiring::Progressive)
+    }
+
+    /// モジュールの総数(チェーン長)
+    pub fn modules(&self) -> usize {
+        self.cols * self.rows
+    }
+
+    /// 横ドット数(論理座標)
+    pub fn width(&self) -> usize {
+        if self.orientation.is_transposed() {
+            self.rows * 8
+        } else {
+            self.cols * 8
+        }
+    }
+
+    /// 縦ドット数(論理座標)
+    pub fn height(&self) -> usize {
+        if self.orientation.is_transposed() {
+            self.cols * 8
+        } else {
+            self.rows * 8
+        }
+    }
+
+    /// 位置(col,row)のモジュールの、桁line・ビット位置bit(0がD7)に当たる論理座標
+    pub fn logical_position(
+        &self,
+        col: usize,
+        row: usize,
+        line: usize,
+        bit: usize,
+    ) -> (usize, usize) {
+        let orientation = self.module_orientation[self.module_index(col, row)];
+        let (mx, my) = orientation.apply(bit, line, 8, 8);
+        self.orientation
+            .invert(col * 8 + mx, row * 8 + my, self.width(), self.height())
+    }
+
+    /// 送信順index番目のモジュールの位置(横位置, 縦位置)
+    pub fn module_position(&self, index: usize) -> (usize, usize) {
+        let row = index / self.cols;
+        let col = index % self.cols;
+        match self.wiring {
+            Wiring::Serpentine if row % 2 == 1 => (self.cols - 1 - col, row),
+            _ => (col, row),
+        }
+    }
+
+    /// 位置(col,row)のモジュールの送信順
+    pub fn module_index(&self, col: usize, row: usize) -> usize {
+        match self.wiring {
+            Wiring::Serpentine if row % 2 == 1 => row * self.cols + self.cols - 1 - col,
+            _ => row * self.cols + col,
+        }
+    }
+}
+
+impl Default for PanelConfig {
+    /// 4モジュール(32*8)
+    fn default() -> Self {
+        Self::chain(4)
+    }
+}
+
+/// フェードの状態
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Fade {
+    fade_out: bool, // true:フェードアウト false:フェードイン
+    elapsed: u32,
+    duration: u32,
+}
+
+impl Fade {
+    fn new(fade_out: bool, duration: u32) -> Fade {
+        Fade {
+            fade_out,
+            elapsed: 0,
+            duration: duration.max(1),
+        }
+    }
+
+    /// 輝度の倍率(0〜256)
+    fn gain(&self) -> u32 {
+        let rest = if self.fade_out {
+            self.duration - self.elapsed
+        } else {
+            self.elapsed
+        };
+        rest * 256 / self.duration
+    }
+
+    /// フェード完了か
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// 送信フレームに含めた内容
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+struct FrameContent {
+    config: bool,
+    video: bool,
+    intensity: bool,
+    shutdown: bool,
+}
+
+impl FrameContent {
+    /// 2つのフレームの内容を合わせる
+    fn merge(self, other: FrameContent) -> FrameContent {
+        FrameContent {
+            config: self.config || other.config,
+            video: self.video || other.video,
+            intensity: self.intensity || other.intensity,
+            shutdown: self.shutdown || other.shutdown,
+        }
+    }
+}
 
 /// Matrix Ledの制御
-pub struct Matrix<'a> {
-    video_ram: [u32; 8], // 左上を基点(0,0)として、各u32のMSBと[0]が基点
-    device: &'a stm32f401::Peripherals,
-    spi: &'a stm32f401::SPI1,
+pub struct Matrix<T: Transport> {
+    video_ram: VideoRam,
+    config: PanelConfig,
+    transport: T,
+    brightness: [u8; MAX_MODULES], // モジュール毎の輝度設定
+    intensity: [u8; MAX_MODULES],  // フェード反映後の輝度(送信する値)
+    fade: Option<Fade>,
+    gain: u32, // フェードによる輝度の倍率(0〜256)
+    shutdown: bool,
+    intensity_dirty: bool,
+    shutdown_dirty: bool,
+    config_dirty: bool,
+    self_heal: SelfHeal,
+    heal_count: u32,      // 前回の再送からのフレーム数、もしくはtick数
+    queued: FrameContent, // 最後にTransportへ渡したフレームの内容
 }
 
-impl<'a> Matrix<'a> {
-    pub(super) fn new(device: &stm32f401::Peripherals) -> Matrix {
-        let led = Matrix {
-            video_ram: [0; 8],
-            device,
-            spi: &device.SPI1,
+impl<T: Transport> Matrix<T> {
+    /// 標準構成(32*8)で初期化する
+    pub fn new(transport: T) -> Matrix<T> {
+        Self::with_config(transport, PanelConfig::default())
+    }
+
+    /// 指定のパネル構成で初期化する
+    ///
+    /// # Panics
+    /// モジュール数が0、もしくはMAX_MODULESを超える場合
+    pub fn with_config(transport: T, config: PanelConfig) -> Matrix<T> {
+        assert!(config.modules() > 0 && config.modules() <= MAX_MODULES);
+        let mut led = Matrix {
+            video_ram: VideoRam::new(config.width(), config.height()),
+            config,
+            transport,
+            brightness: [DEFAULT_BRIGHTNESS; MAX_MODULES],
+            intensity: [DEFAULT_BRIGHTNESS; MAX_MODULES],
+            fade: None,
+            gain: 256,
+            shutdown: false,
+            intensity_dirty: false,
+            shutdown_dirty: false,
+            config_dirty: false,
+            self_heal: SelfHeal::Off,
+            heal_count: 0,
+            queued: FrameContent::default(),
         };
-        led.gpio_setup();
-        led.spi1_setup();
-        led.dma_setup();
         led.init_mat_led();
         led
     }
 
+    /// 送信路への参照
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// パネル構成
+    pub fn config(&self) -> &PanelConfig {
+        &self.config
+    }
+
+    /// 横ドット数
+    pub fn width(&self) -> usize {
+        self.config.width()
+    }
+
+    /// 縦ドット数
+    pub fn height(&self) -> usize {
+        self.config.height()
+    }
+
+    /// 描画用のVideo RAM
+    pub fn video_ram(&self) -> &VideoRam {
+        &self.video_ram
+    }
+
+    /// 描画用のVideo RAM(可変)
+    pub fn video_ram_mut(&mut self) -> &mut VideoRam {
+        &mut self.video_ram
+    }
+
     /// Video RAMをクリアする
     pub fn clear(&mut self) {
-        for line in &mut self.video_ram {
-            *line = 0;
-        }
+        self.video_ram.clear();
     }
 
     /// 指定の場所に、指定の矩形のビットマップを表示する。
@@ -44,357 +463,686 @@ impl<'a> Matrix<'a> {
     /// 幅が8未満の場合は、LSBより詰めること。
     /// 矩形の高さは、bitmapの要素数に等しい。
     pub fn draw_bitmap(&mut self, px: i32, py: u32, width: u32, bitmap: &[u8]) {
-        let width = if width <= 8 { width as i32 } else { 8 };
-        let shift: i32 = 31 - px - width + 1;
-        let mask: u32 = (1 << width) - 1;
-        let mut y = if py >= 8 { return } else { py as usize };
-        for line in bitmap {
-            self.video_ram[y] |= if shift >= 0 {
-                ((*line as u32) & mask) << shift
-            } else {
-                ((*line as u32) & mask) >> -shift
-            };
-            y += 1;
-            if y >= 8 {
-                break;
+        if py as usize >= self.height() {
+            return;
+        }
+        self.video_ram.draw_bitmap(px, py as i32, width, bitmap);
+    }
+
+    /// 任意サイズのビットマップを、指定の合成方法(RasterOp)で描く
+    ///
+    /// bitmapの各行は(width+7)/8バイトで、左端を先頭バイトのMSBとする。
+    /// 座標は負でもよく、パネルの範囲外にはみ出した部分は無視する。
+    pub fn blit(&mut self, px: i32, py: i32, width: u32, height: u32, bitmap: &[u8], op: RasterOp) {
+        self.video_ram.blit(px, py, width, height, bitmap, op);
+    }
+
+    // 以下の描画は、パネルの範囲外にはみ出した部分を無視する。
+    // 引数onは、true:点灯で描く false:消灯で描く
+
+    /// 指定ドットを点灯する
+    pub fn set_pixel(&mut self, x: i32, y: i32) {
+        self.video_ram.put(x, y, true);
+    }
+
+    /// 指定ドットを消灯する
+    pub fn clear_pixel(&mut self, x: i32, y: i32) {
+        self.video_ram.put(x, y, false);
+    }
+
+    /// 指定ドットが点灯しているか。範囲外はfalse
+    pub fn get_pixel(&self, x: i32, y: i32) -> bool {
+        self.video_ram.get(x, y)
+    }
+
+    /// 水平線を描く。(x,y)から右へwidthドット
+    pub fn draw_hline(&mut self, x: i32, y: i32, width: u32, on: bool) {
+        self.video_ram.draw_hline(x, y, width, on);
+    }
+
+    /// 垂直線を描く。(x,y)から下へheightドット
+    pub fn draw_vline(&mut self, x: i32, y: i32, height: u32, on: bool) {
+        self.video_ram.draw_vline(x, y, height, on);
+    }
+
+    /// (x0,y0)から(x1,y1)へ直線を描く
+    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, on: bool) {
+        self.video_ram.draw_line(x0, y0, x1, y1, on);
+    }
+
+    /// 左上(x,y)、幅width、高さheightの矩形の外枠を描く
+    pub fn draw_rect(&mut self, x: i32, y: i32, width: u32, height: u32, on: bool) {
+        self.video_ram.draw_rect(x, y, width, height, on);
+    }
+
+    /// 左上(x,y)、幅width、高さheightの塗りつぶした矩形を描く
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: u32, height: u32, on: bool) {
+        self.video_ram.fill_rect(x, y, width, height, on);
+    }
+
+    /// 中心(cx,cy)、半径rの円を描く
+    pub fn draw_circle(&mut self, cx: i32, cy: i32, r: u32, on: bool) {
+        self.video_ram.draw_circle(cx, cy, r, on);
+    }
+
+    /// 中心(cx,cy)、半径rの塗りつぶした円を描く
+    pub fn fill_circle(&mut self, cx: i32, cy: i32, r: u32, on: bool) {
+        self.video_ram.fill_circle(cx, cy, r, on);
+    }
+
+    /// (x,y)を含む同じ状態の領域(上下左右で連結)を、onで塗りつぶす
+    pub fn flood_fill(&mut self, x: i32, y: i32, on: bool) {
+        self.video_ram.flood_fill(x, y, on);
+    }
+
+    /// チェーン全体の輝度を設定する(0〜MAX_BRIGHTNESS)
+    ///
+    /// 転送中の場合は、転送完了後に反映される。
+    pub fn set_brightness(&mut self, level: u8) -> Result<()> {
+        let level = level.min(MAX_BRIGHTNESS);
+        for b in &mut self.brightness {
+            *b = level;
+        }
+        self.update_intensity();
+        self.update_registers()
+    }
+
+    /// 指定モジュールの輝度を設定する(0〜MAX_BRIGHTNESS)
+    ///
+    /// モジュール番号は、左上を0とし、右へ、次いで下の段へと数える。
+    /// 転送中の場合は、転送完了後に反映される。
+    pub fn set_module_brightness(&mut self, module: usize, level: u8) -> Result<()> {
+        if module >= self.config.modules() {
+            return Err(Error::OutOfRange);
+        }
+        self.brightness[module] = level.min(MAX_BRIGHTNESS);
+        self.update_intensity();
+        self.update_registers()
+    }
+
+    /// 指定モジュールの輝度設定値
+    pub fn brightness(&self, module: usize) -> Result<u8> {
+        if module >= self.config.modules() {
+            return Err(Error::OutOfRange);
+        }
+        Ok(self.brightness[module])
+    }
+
+    /// フェードイン開始
+    ///
+    /// 輝度0から設定値まで、ticks回のtick()で変化させる。(ticksの最小は1)
+    /// シャットダウン中であれば、解除する。
+    pub fn fade_in(&mut self, ticks: u32) {
+        if self.shutdown {
+            self.shutdown = false;
+            self.shutdown_dirty = true;
+        }
+        self.apply_fade(Fade::new(false, ticks));
+    }
+
+    /// フェードアウト開始
+    ///
+    /// 設定値の輝度から0まで、ticks回のtick()で変化させる。(ticksの最小は1)
+    /// 終了時は、シャットダウンモードにして消灯する。
+    pub fn fade_out(&mut self, ticks: u32) {
+        self.apply_fade(Fade::new(true, ticks));
+    }
+
+    /// フェード中か
+    pub fn is_fading(&self) -> bool {
+        self.fade.is_some()
+    }
+
+    /// 時間経過の通知
+    ///
+    /// 一定周期(タイマー割込み等)で呼び出すこと。
+    /// フェード中であれば、輝度を一段階進める。
+    pub fn tick(&mut self) -> Result<()> {
+        if let SelfHeal::EveryTicks(n) = self.self_heal {
+            self.count_heal(n);
+        }
+        if let Some(mut fade) = self.fade {
+            if !fade.is_finished() {
+                fade.elapsed += 1;
             }
+            self.apply_fade(fade);
         }
+        self.update_registers()
     }
 
-    /// Matrix LEDにvideo_ramの内容を表示する。
-    pub fn flash_led(&self) -> Result<()> {
-        // Martix LEDへの転送中判定　及び　転送中フラグセット
-        // このフラグは、DMA2_STREAM3割込み関数にてリセットされる。
-        let is_busy = free(|cs| {
-            let mut flag = DMA_BUSY.borrow(cs).borrow_mut();
-            let ret = *flag;
-            if !*flag {
-                *flag = true;
+    /// 設定レジスタの再送方針を設定する
+    pub fn set_self_heal(&mut self, policy: SelfHeal) {
+        self.self_heal = policy;
+        self.heal_count = 0;
+    }
+
+    /// 設定レジスタの再送方針
+    pub fn self_heal(&self) -> SelfHeal {
+        self.self_heal
+    }
+
+    /// 設定レジスタ(テスト・デコード・スキャン桁・シャットダウン・輝度)を再送する
+    ///
+    /// 転送中の場合は、転送完了後に反映される。
+    pub fn resend_config(&mut self) -> Result<()> {
+        self.config_dirty = true;
+        self.heal_count = 0;
+        self.update_registers()
+    }
+
+    /// 再送周期を数え、周期に達したら再送対象とする
+    fn count_heal(&mut self, interval: u32) {
+        self.heal_count += 1;
+        if self.heal_count >= interval.max(1) {
+            self.heal_count = 0;
+            self.config_dirty = true;
+        }
+    }
+
+    /// フェードの状態を輝度に反映する
+    fn apply_fade(&mut self, fade: Fade) {
+        self.gain = fade.gain();
+        if fade.is_finished() {
+            self.fade = None;
+            if fade.fade_out {
+                self.shutdown = true;
+                self.shutdown_dirty = true;
             }
-            ret
-        });
-        if is_busy {
-            return Err("busy");
+        } else {
+            self.fade = Some(fade);
         }
+        self.update_intensity();
+    }
 
-        if let Err(_) = DMA_BUFF.clear_buff(self.device) {
-            //flash_ledの呼び出しが早すぎるとDMAが復帰していない可能性
-            free(|cs| *DMA_BUSY.borrow(cs).borrow_mut() = false);
-            return Err("DMA busy");
+    /// フェード反映後の輝度を求め、変化があれば送信対象とする
+    fn update_intensity(&mut self) {
+        for module in 0..self.config.modules() {
+            let level = ((self.brightness[module] as u32 * self.gain + 128) >> 8) as u8;
+            if level != self.intensity[module] {
+                self.intensity[module] = level;
+                self.intensity_dirty = true;
+            }
         }
+    }
 
-        for x in 0..8 {
-            self.send_oneline_mat_led(x);
+    /// 変更のあったレジスタ(輝度・シャットダウン・設定)のみを送信する
+    fn update_registers(&mut self) -> Result<()> {
+        if !self.intensity_dirty && !self.shutdown_dirty && !self.config_dirty {
+            return Ok(());
         }
-        self.send_request_to_dma();
-        Ok(())
+        self.send_frame(false)
     }
 
-    /// Matrix LED BUFFに一行を送る
+    /// 1フレームを組み立てて、Transportに渡す
+    /// 変更のあったレジスタは、併せて送る。
     /// # 引数
-    ///     line_num:   一番上が0。一番下が7
-    fn send_oneline_mat_led(&self, line_num: u32) {
-        let digi_code: u16 = ((line_num + 1) << 8) as u16;
-        let pat = self.video_ram[line_num as usize];
-        let dat: [u16; 4] = [
-            digi_code | (((pat >> 24) & 0x00FF) as u16),
-            digi_code | (((pat >> 16) & 0x00FF) as u16),
-            digi_code | (((pat >> 08) & 0x00FF) as u16),
-            digi_code | (((pat) & 0x00FF) as u16),
-        ];
-        DMA_BUFF.add_buff(&dat, self.device).unwrap();
+    ///     video:  video_ramの内容を含めるか
+    fn send_frame(&mut self, video: bool) -> Result<()> {
+        if let (true, SelfHeal::EveryFrames(n)) = (video, self.self_heal) {
+            self.count_heal(n);
+        }
+        // 設定の再送時は、輝度・シャットダウンも送り直す
+        let mut content = FrameContent {
+            config: self.config_dirty,
+            video,
+            intensity: self.intensity_dirty || self.config_dirty,
+            shutdown: self.shutdown_dirty || self.config_dirty,
+        };
+        if self.transport.is_pending() {
+            // 転送待ちのフレームは破棄されるので、その内容も引き継ぐ
+            // 破棄される桁は分からないので、video_ramは全て送り直す
+            if self.queued.video {
+                self.video_ram.invalidate();
+            }
+            content = content.merge(self.queued);
+        }
+
+        if let Err(e) = self.transport.begin() {
+            // 前回までの転送が失敗していれば、表示側の内容は不定
+            self.video_ram.invalidate();
+            return Err(e);
+        }
+        if content.config {
+            self.send_config()?;
+        }
+        if content.intensity {
+            self.send_intensity()?;
+        }
+        if content.shutdown {
+            let pat = REG_SHUTDOWN | if self.shutdown { 0x00 } else { 0x01 };
+            self.send_packet(&[pat; MAX_MODULES][0..self.config.modules()])?;
+        }
+        if content.video {
+            for x in 0..8 {
+                self.send_oneline_mat_led(x)?;
+            }
+        }
+        self.transport.flush()?;
+
+        if content.video {
+            self.video_ram.clear_dirty();
+        }
+        self.intensity_dirty = false;
+        self.shutdown_dirty = false;
+        self.config_dirty = false;
+        self.queued = content;
+        Ok(())
     }
 
-    /// Matrix LED 初期化
-    fn init_mat_led(&self) {
-        const INIT_PAT: [u16; 5] = [
-            0x0F00, // テストモード解除
-            0x0900, // BCDデコードバイパス
-            0x0A02, // 輝度制御　下位4bit MAX:F
-            0x0B07, // スキャン桁指定 下位4bit MAX:7
-            0x0C01, // シャットダウンモード　解除
-        ];
+    /// 輝度のパケットを積む
+    /// 輝度レジスタはチップ毎なので、モジュール毎に異なる値を送る
+    fn send_intensity(&mut self) -> Result<()> {
+        let modules = self.config.modules();
+        let mut dat = [0u16; MAX_MODULES];
+        for (index, word) in dat[0..modules].iter_mut().enumerate() {
+            let (col, row) = self.config.module_position(index);
+            *word = REG_INTENSITY | self.intensity[row * self.config.cols + col] as u16;
+        }
+        self.send_packet(&dat[0..modules])
+    }
+
+    /// video_ramの内容を表示する。
+    ///
+    /// 前回の送信以降に変化した桁のみを送る。
+    /// 転送中であれば、転送完了後に表示される。(完了を待たずに戻る)
+    /// 転送待ちのフレームがあれば、今回のフレームに置き換わる。
+    pub fn present(&mut self) -> Result<()> {
+        self.send_frame(true)
+    }
 
-        while let Err(_) = DMA_BUFF.clear_buff(self.device) {}
-        for pat in &INIT_PAT {
-            DMA_BUFF.add_buff(&[*pat; 4], self.device).unwrap();
-        }
-        self.send_request_to_dma();
-    }
-
-    /// SPI1 データのDMA送信要求
-    ///   MatrixLED 4ブロック*行数 分のデータの送信を行う。
-    ///   送信データは、事前にDMA_BUFFに投入済みのこと。
-    fn send_request_to_dma(&self) {
-        let dma = &self.device.DMA2;
-        let mut i = DMA_BUFF.iter();
-        if let Some(data) = i.next() {
-            while dma.st[3].cr.read().en().is_enabled() {}
-            let adr = data.as_ptr() as u32;
-            dma.st[3].m0ar.write(|w| w.m0a().bits(adr));
-            dma.st[3].ndtr.write(|w| w.ndt().bits(4u16));
-
-            Self::spi_enable(&self.device);
-            Self::dma_start(&self.device);
-        }
-        // 以降、2レコード目からの転送は、割込みルーチンにて
-    }
-
-    /// DMAの完了フラグをクリアし、DMAを開始する
-    fn dma_start(device: &stm32f401::Peripherals) {
-        let dma = &device.DMA2;
-        dma.lifcr.write(|w| {
-            w.ctcif3().clear();
-            w.chtif3().clear();
-            w.cteif3().clear();
-            w.cdmeif3().clear()
-        });
-
-        dma.st[3].cr.modify(|_, w| w.en().enabled());
-    }
-
-    /// spi通信有効にセット
-    fn spi_enable(device: &stm32f401::Peripherals) {
-        let spi = &device.SPI1;
-        Self::cs_enable(&device);
-        spi.cr1.modify(|_, w| w.spe().enabled());
-    }
-
-    /// spi通信無効にセット
-    ///   LEDのデータ確定シーケンス含む
-    fn spi_disable(device: &stm32f401::Peripherals) {
-        let spi = &device.SPI1;
-        while spi.sr.read().txe().is_not_empty() {
-            cortex_m::asm::nop();
-        }
-        while spi.sr.read().bsy().is_busy() {
-            cortex_m::asm::nop(); // wait
-        }
-        Self::cs_disable(&device);
-        spi.cr1.modify(|_, w| w.spe().disabled());
-    }
-
-    /// CS(DATA) ピンを 通信無効(HI)にする
-    /// CSピンは、PA4に固定(ハードコート)
-    fn cs_disable(device: &stm32f401::Peripherals) {
-        device.GPIOA.bsrr.write(|w| w.bs4().set());
-        for _x in 0..5 {
-            // 通信終了時は、データの確定待ちが必要
-            // 最低50ns 48MHzクロックで最低3クロック
-            cortex_m::asm::nop();
-        }
-    }
-
-    /// CS(DATA) ピンを通信有効(LO)にする
-    /// CSピンは、PA4に固定(ハードコート)
-    fn cs_enable(device: &stm32f401::Peripherals) {
-        device.GPIOA.bsrr.write(|w| w.br4().reset());
-    }
-
-    /// SPIのセットアップ
-    fn spi1_setup(&self) {
-        // 電源投入
-        self.device.RCC.apb2enr.modify(|_, w| w.spi1en().enabled());
-
-        self.spi.cr1.modify(|_, w| {
-            w.bidimode().unidirectional().
-            dff().sixteen_bit().
-            lsbfirst().msbfirst().
-            br().div4(). // 基準クロックは48MHz
-            mstr().master().
-            cpol().idle_low().
-            cpha().first_edge().
-            ssm().enabled().
-            ssi().slave_not_selected()
-        });
-        self.spi.cr2.modify(|_, w| w.txdmaen().enabled());
-    }
-
-    /// gpioのセットアップ
-    fn gpio_setup(&self) {
-        self.device.RCC.ahb1enr.modify(|_, w| w.gpioaen().enabled());
-        // SPI端子割付け
-        let gpioa = &self.device.GPIOA;
-        gpioa.moder.modify(|_, w| w.moder7().alternate()); // SPI1_MOSI
-        gpioa.afrl.modify(|_, w| w.afrl7().af5());
-        gpioa.ospeedr.modify(|_, w| w.ospeedr7().very_high_speed());
-        gpioa.otyper.modify(|_, w| w.ot7().push_pull());
-        gpioa.moder.modify(|_, w| w.moder5().alternate()); // SPI1_CLK
-        gpioa.afrl.modify(|_, w| w.afrl5().af5());
-        gpioa.ospeedr.modify(|_, w| w.ospeedr5().very_high_speed());
-        gpioa.otyper.modify(|_, w| w.ot5().push_pull());
-        gpioa.moder.modify(|_, w| w.moder4().output()); // NSS(CS)
-        gpioa.ospeedr.modify(|_, w| w.ospeedr4().very_high_speed());
-        gpioa.otyper.modify(|_, w| w.ot4().push_pull());
-    }
-
-    /// DMAのセットアップ
-    fn dma_setup(&self) {
-        self.device.RCC.ahb1enr.modify(|_, w| w.dma2en().enabled());
-        // DMAストリーム3のチャンネル3使用
-        let st3_3 = &self.device.DMA2.st[3];
-        st3_3.cr.modify(|_, w| {
-            w.chsel().bits(3u8);
-            w.mburst().incr4();
-            w.pburst().single();
-            w.ct().memory0();
-            w.dbm().disabled();
-            w.pl().medium();
-            w.pincos().psize();
-            w.msize().bits16();
-            w.psize().bits16();
-            w.minc().incremented();
-            w.pinc().fixed();
-            w.circ().disabled();
-            w.dir().memory_to_peripheral();
-            w.tcie().enabled();
-            w.htie().disabled();
-            w.teie().disabled();
-            w.dmeie().disabled()
-        });
-        st3_3.fcr.modify(|_, w| {
-            w.feie().disabled();
-            w.dmdis().disabled();
-            w.fth().half()
-        });
-        let spi1_dr = &self.device.SPI1.dr as *const _ as u32;
-        st3_3.par.write(|w| w.pa().bits(spi1_dr));
-        unsafe {
-            cortex_m::peripheral::NVIC::unmask(stm32f401::interrupt::DMA2_STREAM3);
+    /// 設定レジスタとvideo_ramの全ての桁を、1フレームで送り直す
+    ///
+    /// 送ったフレームだけで表示内容が決まるので、スナップショットの取得にも使える。
+    pub fn present_full(&mut self) -> Result<()> {
+        self.config_dirty = true;
+        self.video_ram.invalidate();
+        self.send_frame(true)
+    }
+
+    /// 次回の表示で、video_ramの全ての桁を送り直す
+    ///
+    /// 表示側の内容が、ノイズ等で崩れた可能性がある場合に使う。
+    pub fn invalidate(&mut self) {
+        self.video_ram.invalidate();
+    }
+
+    /// Matrix LEDにvideo_ramの内容を表示する。
+    /// 転送中であれば、何もせずにErr(Error::Busy)を返す。
+    pub fn flash_led(&mut self) -> Result<()> {
+        if self.transport.is_busy() {
+            return Err(Error::Busy);
         }
+        self.present()
+    }
+
+    /// Matrix LEDに一行を送る
+    /// 各モジュールの同じ桁(行)をまとめて1パケットとする
+    /// 変化の無いモジュールはNo-opとし、全て変化が無ければ送らない。
+    /// # 引数
+    ///     line_num:   MAX7219の桁(モジュールの向きが標準なら、一番上が0。一番下が7)
+    fn send_oneline_mat_led(&mut self, line_num: usize) -> Result<()> {
+        let digi_code: u16 = ((line_num + 1) << 8) as u16;
+        let modules = self.config.modules();
+        let mut dat = [REG_NO_OP; MAX_MODULES];
+        let mut changed = false;
+        for (index, word) in dat[0..modules].iter_mut().enumerate() {
+            let (col, row) = self.config.module_position(index);
+            if let Some(pat) = self.module_line(col, row, line_num) {
+                *word = digi_code | pat as u16;
+                changed = true;
+            }
+        }
+        if !changed {
+            return Ok(());
+        }
+        self.send_packet(&dat[0..modules])
     }
-}
 
-/// DMA2 Stream3 割込み関数
-#[interrupt]
-fn DMA2_STREAM3() {
-    static mut ITER: Option<DmaBuffIter> = None;
-
-    let device;
-    unsafe {
-        device = stm32f401::Peripherals::steal();
-    }
-    let dma = &device.DMA2;
-    if dma.lisr.read().tcif3().is_complete() {
-        dma.lifcr.write(|w| w.ctcif3().clear());
-        if let None = ITER {
-            *ITER = Some(DMA_BUFF.iter());
-            ITER.as_mut().unwrap().next();
-        }
-        match ITER.as_mut().unwrap().next() {
-            Some(data) => {
-                //次のデータの準備
-                let adr = data.as_ptr() as u32;
-                dma.st[3].m0ar.write(|w| w.m0a().bits(adr));
-                dma.st[3].ndtr.write(|w| w.ndt().bits(4u16));
-
-                //前データの確定終了処理
-                Matrix::spi_disable(&device);
-
-                //次のデータの送信開始
-                Matrix::spi_enable(&device);
-                Matrix::dma_start(&device);
+    /// 位置(col,row)のモジュールの桁lineに送るパターン
+    /// 前回の送信以降に変化が無ければNone
+    fn module_line(&self, col: usize, row: usize, line: usize) -> Option<u8> {
+        let index = self.config.module_index(col, row);
+        if self.config.orientation.is_identity()
+            && self.config.module_orientation[index].is_identity()
+        {
+            let y = row * 8 + line;
+            if !self.video_ram.is_dirty(col, y) {
+                return None;
             }
-            None => {
-                //前データの確定終了処理
-                Matrix::spi_disable(&device);
-                *ITER = None;
-                free(|cs| *DMA_BUSY.borrow(cs).borrow_mut() = false);
+            return Some(self.video_ram.byte(col, y));
+        }
+        // 向きの変換がある場合は、1ドット毎に論理座標から拾う
+        let mut pat = 0u8;
+        let mut dirty = false;
+        for bit in 0..8 {
+            let (x, y) = self.config.logical_position(col, row, line, bit);
+            if self.video_ram.get(x as i32, y as i32) {
+                pat |= 0x80 >> bit;
             }
+            dirty |= self.video_ram.is_dirty(x / 8, y);
         }
-    } else {
-        dma.lifcr.write(|w| {
-            w.ctcif3().clear();
-            w.chtif3().clear();
-            w.cteif3().clear();
-            w.cdmeif3().clear()
-        });
+        if dirty {
+            Some(pat)
+        } else {
+            None
+        }
+    }
+
+    /// 1パケット(CS有効〜無効)を送る
+    fn send_packet(&mut self, dat: &[u16]) -> Result<()> {
+        self.transport.select()?;
+        self.transport.write(dat)?;
+        self.transport.deselect()
+    }
+
+    /// 設定レジスタ(テスト・デコード・スキャン桁)のパケットを積む
+    fn send_config(&mut self) -> Result<()> {
+        let modules = self.config.modules();
+        for pat in &CONFIG_PAT {
+            self.send_packet(&[*pat; MAX_MODULES][0..modules])?;
+        }
+        Ok(())
+    }
+
+    /// Matrix LED 初期化
+    fn init_mat_led(&mut self) {
+        let modules = self.config.modules();
+        while self.transport.begin().is_err() {}
+        self.send_config().unwrap();
+        let pat = REG_SHUTDOWN | 0x01; // シャットダウンモード　解除
+        self.send_packet(&[pat; MAX_MODULES][0..modules]).unwrap();
+        self.send_intensity().unwrap(); // 輝度制御　下位4bit MAX:F
+        self.transport.flush().unwrap();
     }
 }
 
-/// DMAビジーフラグ
-static DMA_BUSY: Mutex<RefCell<bool>> = Mutex::new(RefCell::new(false));
+impl<T: Transport> LedPanel for Matrix<T> {
+    fn video_ram(&self) -> &VideoRam {
+        &self.video_ram
+    }
 
-/// DMAバッファ領域
-/// 　グローバル変数・matrix_ledモジュール以外での操作禁止
-///   DMA2_S3CR.ENビットが0の時のみ操作可能
-static DMA_BUFF: DmaBuff = DMA_BUFF_INIT;
+    fn video_ram_mut(&mut self) -> &mut VideoRam {
+        &mut self.video_ram
+    }
+
+    fn present(&mut self) -> Result<()> {
+        Matrix::present(self)
+    }
+
+    fn set_brightness(&mut self, level: u8) -> Result<()> {
+        Matrix::set_brightness(self, level)
+    }
+
+    fn is_busy(&self) -> bool {
+        self.transport.is_busy()
+    }
+
+    fn tick(&mut self) -> Result<()> {
+        Matrix::tick(self)
+    }
 
-use core::cell::UnsafeCell;
-struct DmaBuff {
-    buff: UnsafeCell<[[u16; 4]; 8]>,
-    data_count: UnsafeCell<usize>,
+    fn width(&self) -> usize {
+        Matrix::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Matrix::height(self)
+    }
 }
 
-const DMA_BUFF_INIT: DmaBuff = DmaBuff {
-    buff: UnsafeCell::new([[0u16; 4]; 8]),
-    data_count: UnsafeCell::new(0),
-};
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::RecordingTransport;
 
-unsafe impl Sync for DmaBuff {}
+    /// 送信したパケットの一覧
+    fn packets(m: &Matrix<RecordingTransport>) -> Vec<Vec<u16>> {
+        m.transport().packets().map(|p| p.to_vec()).collect()
+    }
 
-impl DmaBuff {
-    pub fn clear_buff(&self, device: &stm32f401::Peripherals) -> Result<()> {
-        Self::is_dma_inactive(device)?;
-        unsafe {
-            *self.data_count.get() = 0;
+    #[test]
+    fn init_sequence() {
+        let m = Matrix::new(RecordingTransport::new());
+        let expected: [u16; 5] = [0x0F00, 0x0900, 0x0B07, 0x0C01, 0x0A02];
+        let sent = packets(&m);
+        assert_eq!(sent.len(), expected.len());
+        for (packet, word) in sent.iter().zip(expected.iter()) {
+            assert_eq!(packet, &[*word; 4]);
         }
-        Ok(())
+        assert_eq!(m.transport().flush_count(), 1);
     }
 
-    pub fn add_buff(&self, data: &[u16], device: &stm32f401::Peripherals) -> Result<()> {
-        Self::is_dma_inactive(device)?;
-        unsafe {
-            if *self.data_count.get() < 8 {
-                *self.data_count.get() += 1;
-            } else {
-                return Err("Buffer over flow");
-            }
-            &(*self.buff.get())[*self.data_count.get() - 1].clone_from_slice(&data[0..4]);
-        }
-        Ok(())
+    #[test]
+    fn present_sends_dirty_rows_only() {
+        let mut m = Matrix::new(RecordingTransport::new());
+        m.present().unwrap();
+        assert_eq!(m.transport().packets().count(), 5 + 8);
+        // 変化がなければ送らない
+        m.present().unwrap();
+        assert_eq!(m.transport().packets().count(), 5 + 8);
+        // (9,3)は、2番目のモジュールの4行目。先頭のワードが終端のモジュール
+        m.set_pixel(9, 3);
+        m.present().unwrap();
+        let sent = packets(&m);
+        assert_eq!(sent.len(), 5 + 8 + 1);
+        assert_eq!(sent[13], [0x0000, 0x0440, 0x0000, 0x0000]);
+        // invalidate後は全行を送る
+        m.invalidate();
+        m.present().unwrap();
+        assert_eq!(m.transport().packets().count(), 5 + 8 + 1 + 8);
     }
 
-    pub fn iter(&self) -> DmaBuffIter {
-        DmaBuffIter { cur_index: None }
+    #[test]
+    fn serpentine_mapping() {
+        let config = PanelConfig::new(2, 2, Wiring::Serpentine);
+        assert_eq!((config.width(), config.height()), (16, 16));
+        // 2段目は右から左へ並ぶ
+        assert_eq!(config.module_position(0), (0, 0));
+        assert_eq!(config.module_position(1), (1, 0));
+        assert_eq!(config.module_position(2), (1, 1));
+        assert_eq!(config.module_position(3), (0, 1));
+        let mut m = Matrix::with_config(RecordingTransport::new(), config);
+        m.draw_bitmap(8, 8, 8, &[0xf0]);
+        m.present().unwrap();
+        let sent = packets(&m);
+        // 右下のモジュール(index 2)は、送信順で3番目のワード
+        assert_eq!(sent[5], [0x0100, 0x0100, 0x01f0, 0x0100]);
     }
 
-    fn is_dma_inactive(device: &stm32f401::Peripherals) -> Result<()> {
-        if device.DMA2.st[3].cr.read().en().is_enabled() {
-            Err("DMA2 stream active")
-        } else {
-            Ok(())
+    /// 全ての回転・反転の組み合わせ
+    fn orientations() -> Vec<Orientation> {
+        let rotations = [
+            Rotation::Deg0,
+            Rotation::Deg90,
+            Rotation::Deg180,
+            Rotation::Deg270,
+        ];
+        let mut all = Vec::new();
+        for &rotation in &rotations {
+            for &(mirror_x, mirror_y) in
+                &[(false, false), (true, false), (false, true), (true, true)]
+            {
+                all.push(Orientation::new(rotation, mirror_x, mirror_y));
+            }
         }
+        all
     }
 
-    fn get_buff(&self, index: usize) -> Option<&[u16; 4]> {
-        unsafe {
-            if index < *self.data_count.get() {
-                Some(&(*self.buff.get())[index])
+    #[test]
+    fn orientation_round_trip() {
+        let (width, height) = (16, 8);
+        for o in orientations() {
+            let (tw, th) = if o.is_transposed() {
+                (height, width)
             } else {
-                None
+                (width, height)
+            };
+            let mut seen = vec![false; width * height];
+            for y in 0..height {
+                for x in 0..width {
+                    let (px, py) = o.apply(x, y, width, height);
+                    assert!(px < tw && py < th, "{:?}", o);
+                    assert!(!seen[py * tw + px], "{:?}", o);
+                    seen[py * tw + px] = true;
+                    assert_eq!(o.invert(px, py, width, height), (x, y), "{:?}", o);
+                }
             }
         }
+        let o = Orientation::rotate(Rotation::Deg90);
+        assert_eq!(o.apply(0, 0, 16, 8), (7, 0));
+        let o = Orientation::new(Rotation::Deg0, true, false);
+        assert_eq!(o.apply(0, 0, 16, 8), (15, 0));
     }
-}
 
-/// DmaBuff用Iterator
-struct DmaBuffIter {
-    cur_index: Option<usize>,
-}
+    #[test]
+    fn logical_position() {
+        let config = PanelConfig::chain(2);
+        assert_eq!(config.logical_position(1, 0, 3, 0), (8, 3));
+        assert_eq!(config.logical_position(1, 0, 3, 7), (15, 3));
+        // パネル全体を逆さまに取り付け
+        let config = PanelConfig::chain(2).with_orientation(Orientation::rotate(Rotation::Deg180));
+        assert_eq!(config.logical_position(0, 0, 0, 0), (15, 7));
+        // 縦置き(論理座標は8*16)
+        let config = PanelConfig::chain(2).with_orientation(Orientation::rotate(Rotation::Deg90));
+        assert_eq!((config.width(), config.height()), (8, 16));
+        assert_eq!(config.logical_position(0, 0, 0, 0), (0, 15));
+        assert_eq!(config.logical_position(1, 0, 7, 7), (7, 0));
+        // 2番目のモジュールのみ180°回転
+        let mut config = PanelConfig::chain(2);
+        config
+            .set_module_orientation(1, Orientation::rotate(Rotation::Deg180))
+            .unwrap();
+        assert_eq!(config.logical_position(0, 0, 0, 0), (0, 0));
+        assert_eq!(config.logical_position(1, 0, 0, 0), (15, 7));
+        assert_eq!(
+            config.set_module_orientation(2, Orientation::IDENTITY),
+            Err(Error::OutOfRange)
+        );
+        // 送信順と位置の対応
+        let config = PanelConfig::new(3, 2, Wiring::Serpentine);
+        for index in 0..config.modules() {
+            let (col, row) = config.module_position(index);
+            assert_eq!(config.module_index(col, row), index);
+        }
+    }
 
-impl Iterator for DmaBuffIter {
-    type Item = &'static [u16; 4];
+    #[test]
+    fn per_module_orientation() {
+        let mut orientations = [Orientation::IDENTITY; MAX_MODULES];
+        orientations[1] = Orientation::rotate(Rotation::Deg180);
+        let config = PanelConfig::chain(2).with_module_orientations(orientations);
+        let mut m = Matrix::with_config(RecordingTransport::new(), config);
+        m.present().unwrap();
+        m.set_pixel(8, 0);
+        m.set_pixel(0, 0);
+        m.present().unwrap();
+        let sent = packets(&m);
+        let n = sent.len();
+        // 回転したモジュールでは、左上のドットが桁7のD0になる
+        assert_eq!(sent[n - 2], [0x0180, 0x0000]);
+        assert_eq!(sent[n - 1], [0x0000, 0x0801]);
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match &mut self.cur_index {
-            Some(i) => {
-                *i += 1;
-            }
-            None => {
-                self.cur_index = Some(0);
+    /// 設定の再送で送るパケット
+    const HEAL: [[u16; 4]; 5] = [
+        [0x0F00; 4],
+        [0x0900; 4],
+        [0x0B07; 4],
+        [0x0A02; 4],
+        [0x0C01; 4],
+    ];
+
+    #[test]
+    fn self_heal_every_frames() {
+        let mut m = Matrix::new(RecordingTransport::new());
+        m.present().unwrap();
+        m.set_self_heal(SelfHeal::EveryFrames(3));
+        m.transport.clear();
+        for _ in 0..2 {
+            for _ in 0..2 {
+                m.present().unwrap();
+                assert_eq!(m.transport().packets().count(), 0);
             }
-        };
-        DMA_BUFF.get_buff(self.cur_index.unwrap())
+            // tick()は数えない
+            m.tick().unwrap();
+            assert_eq!(m.transport().packets().count(), 0);
+            m.present().unwrap();
+            assert_eq!(packets(&m), HEAL);
+            m.transport.clear();
+        }
+    }
+
+    #[test]
+    fn self_heal_every_ticks() {
+        let mut m = Matrix::new(RecordingTransport::new());
+        m.present().unwrap();
+        m.set_self_heal(SelfHeal::EveryTicks(2));
+        m.transport.clear();
+        for _ in 0..2 {
+            m.tick().unwrap();
+            // present()は数えない
+            m.present().unwrap();
+            assert_eq!(m.transport().packets().count(), 0);
+            m.tick().unwrap();
+            assert_eq!(packets(&m), HEAL);
+            m.transport.clear();
+        }
+        m.set_self_heal(SelfHeal::Off);
+        for _ in 0..4 {
+            m.tick().unwrap();
+        }
+        assert_eq!(m.transport().packets().count(), 0);
+    }
+
+    #[test]
+    fn module_brightness() {
+        let config = PanelConfig::new(2, 2, Wiring::Serpentine);
+        let mut m = Matrix::with_config(RecordingTransport::new(), config);
+        m.set_brightness(15).unwrap();
+        // モジュール2は左下。送信順では4番目
+        m.set_module_brightness(2, 3).unwrap();
+        assert_eq!(m.brightness(2), Ok(3));
+        assert_eq!(m.brightness(1), Ok(15));
+        let sent = packets(&m);
+        assert_eq!(sent[sent.len() - 1], [0x0A0F, 0x0A0F, 0x0A0F, 0x0A03]);
+        assert_eq!(m.brightness(4), Err(Error::OutOfRange));
+        assert_eq!(m.brightness(MAX_MODULES), Err(Error::OutOfRange));
+        assert_eq!(m.set_module_brightness(4, 1), Err(Error::OutOfRange));
+    }
+
+    #[test]
+    fn fade_steps() {
+        let mut m = Matrix::new(RecordingTransport::new());
+        m.set_brightness(15).unwrap();
+        m.transport.clear();
+        // 開始時は送らず、tick()毎に一段階ずつ送る
+        m.fade_in(4);
+        assert!(m.is_fading());
+        assert_eq!(m.transport().packets().count(), 0);
+        for level in [0x04, 0x08, 0x0B, 0x0F] {
+            m.tick().unwrap();
+            assert_eq!(packets(&m), [[REG_INTENSITY | level; 4]]);
+            m.transport.clear();
+        }
+        assert!(!m.is_fading());
+
+        m.fade_out(4);
+        for level in [0x0B, 0x08, 0x04] {
+            m.tick().unwrap();
+            assert_eq!(packets(&m), [[REG_INTENSITY | level; 4]]);
+            m.transport.clear();
+        }
+        // 終了時は、輝度0とシャットダウンを送る
+        m.tick().unwrap();
+        assert_eq!(packets(&m), [[0x0A00; 4], [0x0C00; 4]]);
+        assert!(!m.is_fading());
+        m.transport.clear();
+        m.tick().unwrap();
+        assert_eq!(m.transport().packets().count(), 0);
+
+        // フェードインで、シャットダウンを解除する
+        m.fade_in(2);
+        m.tick().unwrap();
+        assert_eq!(packets(&m), [[0x0A08; 4], [0x0C01; 4]]);
     }
 }
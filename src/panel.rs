@@ -0,0 +1,39 @@
+//! LEDパネルの共通インターフェース
+//!
+//! 描画用のvideo_ram(1ドット1bit)と、その内容を表示する手段を持つもの。
+//! DisplayLed等は、このインターフェースを通してパネルを使うので、
+//! MAX7219(Matrix)以外のドライバでも同じように使える。
+
+use crate::matrix_led::Result;
+use crate::video_ram::VideoRam;
+
+/// LEDパネル
+pub trait LedPanel {
+    /// 描画用のVideo RAM
+    fn video_ram(&self) -> &VideoRam;
+    /// 描画用のVideo RAM(可変)
+    fn video_ram_mut(&mut self) -> &mut VideoRam;
+    /// video_ramの内容を表示する
+    /// 転送中であれば、転送完了後に表示される。(完了を待たずに戻る)
+    fn present(&mut self) -> Result<()>;
+    /// パネル全体の輝度を設定する(0〜MAX_BRIGHTNESS)
+    fn set_brightness(&mut self, level: u8) -> Result<()>;
+    /// 転送中か
+    fn is_busy(&self) -> bool;
+
+    /// 時間経過の通知(フェード等の進行)
+    /// 一定周期(タイマー割込み等)で呼び出すこと。
+    fn tick(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// 横ドット数
+    fn width(&self) -> usize {
+        self.video_ram().width()
+    }
+
+    /// 縦ドット数
+    fn height(&self) -> usize {
+        self.video_ram().height()
+    }
+}
@@ -0,0 +1,612 @@
+//! WS2812(NeoPixel)によるRGB Matrix LEDの制御
+//!  1ドット1画素(24bit)のフレームバッファを持ち、present()で全画素を送る。
+//!
+//! 描画は、Matrixと同じく論理座標(orientation適用前)で行う。
+//! 線・円等の図形は、VideoRamに描いたものを指定の色で塗る。
+//!
+//! WS2812には輝度の設定が無いので、送信時に各色の値を輝度に応じて縮小する。
+//! 輝度の段階は、Matrixと同じく0〜MAX_BRIGHTNESS。
+//!
+//! LedPanelとしては、1ドット1bitのvideo_ramを持ち、点灯したドットを文字色(pen)、
+//! 消灯したドットを背景色(paper)で塗ってから送る。(DisplayLedで文字を表示できる)
+
+use crate::matrix_led::{Orientation, Result, Wiring, MAX_BRIGHTNESS};
+use crate::panel::LedPanel;
+use crate::transport::PixelTransport;
+use crate::video_ram::{VideoRam, VRAM_SIZE};
+
+/// 接続可能な最大画素数(32*8パネル 2枚分)
+pub const MAX_PIXELS: usize = 512;
+
+/// 輝度の初期値
+const DEFAULT_BRIGHTNESS: u8 = 0x02;
+
+/// 1回のwrite()で送る画素数
+const WRITE_CHUNK: usize = 32;
+
+/// 画素の色
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+    pub const RED: Rgb = Rgb::new(255, 0, 0);
+    pub const GREEN: Rgb = Rgb::new(0, 255, 0);
+    pub const BLUE: Rgb = Rgb::new(0, 0, 255);
+    pub const YELLOW: Rgb = Rgb::new(255, 255, 0);
+    pub const CYAN: Rgb = Rgb::new(0, 255, 255);
+    pub const MAGENTA: Rgb = Rgb::new(255, 0, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    /// 輝度(0〜MAX_BRIGHTNESS)に応じて縮小した色
+    ///   (level+1)/16倍とする
+    pub fn scale(self, level: u8) -> Rgb {
+        let level = level.min(MAX_BRIGHTNESS) as u16 + 1;
+        let scale = |c: u8| ((c as u16 * level) / (MAX_BRIGHTNESS as u16 + 1)) as u8;
+        Rgb::new(scale(self.r), scale(self.g), scale(self.b))
+    }
+}
+
+/// 画素の並ぶ方向(物理)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Scan {
+    /// 行毎(左から右へ並び、次の行へ)
+    Rows,
+    /// 列毎(上から下へ並び、次の列へ)
+    Columns,
+}
+
+/// RGBパネル構成
+///
+/// 画素の順番は、チェーンの先頭(マイコンに最も近い)を0とし、
+/// 物理的な左上隅から、scanの方向に並ぶ。
+/// wiringがSerpentineの場合、奇数番目の行(列)は逆向きに並ぶ。
+///   orientation: 論理座標から、パネル上の物理的な位置への変換
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RgbPanelConfig {
+    pub width: usize,  // 横ドット数(物理)
+    pub height: usize, // 縦ドット数(物理)
+    pub scan: Scan,
+    pub wiring: Wiring,
+    pub orientation: Orientation,
+}
+
+impl RgbPanelConfig {
+    pub const fn new(width: usize, height: usize, scan: Scan, wiring: Wiring) -> RgbPanelConfig {
+        RgbPanelConfig {
+            width,
+            height,
+            scan,
+            wiring,
+            orientation: Orientation::IDENTITY,
+        }
+    }
+
+    /// パネル全体の向きを指定する
+    pub const fn with_orientation(self, orientation: Orientation) -> RgbPanelConfig {
+        RgbPanelConfig {
+            orientation,
+            ..self
+        }
+    }
+
+    /// 画素の総数(チェーン長)
+    pub fn pixels(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// 横ドット数(論理座標)
+    pub fn logical_width(&self) -> usize {
+        if self.orientation.is_transposed() {
+            self.height
+        } else {
+            self.width
+        }
+    }
+
+    /// 縦ドット数(論理座標)
+    pub fn logical_height(&self) -> usize {
+        if self.orientation.is_transposed() {
+            self.width
+        } else {
+            self.height
+        }
+    }
+
+    /// チェーンのindex番目の画素の論理座標
+    pub fn pixel_position(&self, index: usize) -> (usize, usize) {
+        let (line, pos, len) = match self.scan {
+            Scan::Rows => (index / self.width, index % self.width, self.width),
+            Scan::Columns => (index / self.height, index % self.height, self.height),
+        };
+        let pos = match self.wiring {
+            Wiring::Serpentine if line % 2 == 1 => len - 1 - pos,
+            _ => pos,
+        };
+        let (x, y) = match self.scan {
+            Scan::Rows => (pos, line),
+            Scan::Columns => (line, pos),
+        };
+        self.orientation
+            .invert(x, y, self.logical_width(), self.logical_height())
+    }
+}
+
+impl Default for RgbPanelConfig {
+    /// 32*8 列毎に折り返すパネル(市販の32*8 WS2812パネル)
+    fn default() -> Self {
+        Self::new(32, 8, Scan::Columns, Wiring::Serpentine)
+    }
+}
+
+/// RGB Matrix LED
+pub struct RgbMatrix<T: PixelTransport> {
+    transport: T,
+    config: RgbPanelConfig,
+    buff: [Rgb; MAX_PIXELS], // 各ドットの色(論理座標で左上から行毎)
+    brightness: u8,
+    dirty: bool,     // 前回の送信以降に変化したか
+    layer: VideoRam, // LedPanelとしてのvideo_ram
+    pen: Rgb,        // layerの点灯ドットの色
+    paper: Rgb,      // layerの消灯ドットの色
+}
+
+impl<T: PixelTransport> RgbMatrix<T> {
+    /// 標準のパネル構成(32*8)で初期化する
+    pub fn new(transport: T) -> RgbMatrix<T> {
+        Self::with_config(transport, RgbPanelConfig::default())
+    }
+
+    /// 指定のパネル構成で初期化する
+    ///
+    /// # Panics
+    /// 画素数が0、もしくはMAX_PIXELSを超える場合。
+    /// 図形描画用のVideoRamに収まらない場合
+    pub fn with_config(transport: T, config: RgbPanelConfig) -> RgbMatrix<T> {
+        let (width, height) = (config.logical_width(), config.logical_height());
+        assert!(config.pixels() > 0 && config.pixels() <= MAX_PIXELS);
+        assert!(width.div_ceil(8) * height <= VRAM_SIZE);
+        let mut led = RgbMatrix {
+            transport,
+            config,
+            buff: [Rgb::BLACK; MAX_PIXELS],
+            brightness: DEFAULT_BRIGHTNESS,
+            dirty: true,
+            layer: VideoRam::new(width.div_ceil(8) * 8, height),
+            pen: Rgb::WHITE,
+            paper: Rgb::BLACK,
+        };
+        // 送れなかった場合は、dirtyのまま残り、次のpresent()で送られる
+        let _ = led.present();
+        led
+    }
+
+    /// 送信路への参照
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// パネル構成
+    pub fn config(&self) -> &RgbPanelConfig {
+        &self.config
+    }
+
+    /// 横ドット数(論理座標)
+    pub fn width(&self) -> usize {
+        self.config.logical_width()
+    }
+
+    /// 縦ドット数(論理座標)
+    pub fn height(&self) -> usize {
+        self.config.logical_height()
+    }
+
+    /// 全ドットを黒(消灯)にする
+    pub fn clear(&mut self) {
+        self.fill(Rgb::BLACK);
+    }
+
+    /// 全ドットを指定の色にする
+    pub fn fill(&mut self, color: Rgb) {
+        let pixels = self.config.pixels();
+        for dot in &mut self.buff[0..pixels] {
+            if *dot != color {
+                *dot = color;
+                self.dirty = true;
+            }
+        }
+    }
+
+    // 以下の描画は、パネルの範囲外にはみ出した部分を無視する。
+
+    /// 指定ドットを指定の色にする
+    pub fn set_pixel(&mut self, x: i32, y: i32, color: Rgb) {
+        if let Some(i) = self.index(x, y) {
+            if self.buff[i] != color {
+                self.buff[i] = color;
+                self.dirty = true;
+            }
+        }
+    }
+
+    /// 指定ドットの色。範囲外は黒
+    pub fn get_pixel(&self, x: i32, y: i32) -> Rgb {
+        match self.index(x, y) {
+            Some(i) => self.buff[i],
+            None => Rgb::BLACK,
+        }
+    }
+
+    /// 指定の場所に、指定の矩形のビットマップを指定の色で描く。
+    ///
+    /// Matrix::draw_bitmapと同じく、最大幅は8で、幅が8未満の場合はLSBより詰めること。
+    /// 0のドットは変えない。
+    pub fn draw_bitmap(&mut self, px: i32, py: i32, width: u32, bitmap: &[u8], color: Rgb) {
+        self.stroke(color, |mask| mask.draw_bitmap(px, py, width, bitmap));
+    }
+
+    /// VideoRamの点灯しているドットを、同じ座標に指定の色で描く
+    ///
+    /// Matrix向けに描いたものを、そのまま色付けして表示できる。
+    pub fn paint(&mut self, mask: &VideoRam, color: Rgb) {
+        for y in 0..mask.height() as i32 {
+            for x in 0..mask.width() as i32 {
+                if mask.get(x, y) {
+                    self.set_pixel(x, y, color);
+                }
+            }
+        }
+    }
+
+    /// 水平線を描く。(x,y)から右へwidthドット
+    pub fn draw_hline(&mut self, x: i32, y: i32, width: u32, color: Rgb) {
+        self.stroke(color, |mask| mask.draw_hline(x, y, width, true));
+    }
+
+    /// 垂直線を描く。(x,y)から下へheightドット
+    pub fn draw_vline(&mut self, x: i32, y: i32, height: u32, color: Rgb) {
+        self.stroke(color, |mask| mask.draw_vline(x, y, height, true));
+    }
+
+    /// (x0,y0)から(x1,y1)への直線を描く
+    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, color: Rgb) {
+        self.stroke(color, |mask| mask.draw_line(x0, y0, x1, y1, true));
+    }
+
+    /// 矩形の枠を描く
+    pub fn draw_rect(&mut self, x: i32, y: i32, width: u32, height: u32, color: Rgb) {
+        self.stroke(color, |mask| mask.draw_rect(x, y, width, height, true));
+    }
+
+    /// 矩形を塗りつぶす
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: u32, height: u32, color: Rgb) {
+        self.stroke(color, |mask| mask.fill_rect(x, y, width, height, true));
+    }
+
+    /// 中心(cx,cy)、半径rの円を描く
+    pub fn draw_circle(&mut self, cx: i32, cy: i32, r: u32, color: Rgb) {
+        self.stroke(color, |mask| mask.draw_circle(cx, cy, r, true));
+    }
+
+    /// 中心(cx,cy)、半径rの円を塗りつぶす
+    pub fn fill_circle(&mut self, cx: i32, cy: i32, r: u32, color: Rgb) {
+        self.stroke(color, |mask| mask.fill_circle(cx, cy, r, true));
+    }
+
+    /// (x,y)を含む同じ色の領域(上下左右で連結)を、指定の色で塗りつぶす
+    pub fn flood_fill(&mut self, x: i32, y: i32, color: Rgb) {
+        if self.index(x, y).is_none() {
+            return;
+        }
+        let target = self.get_pixel(x, y);
+        // 同じ色のドットのマスクを作り、連結した部分を消す
+        let mut same = self.new_mask();
+        let mut rest = self.new_mask();
+        for py in 0..self.height() as i32 {
+            for px in 0..self.width() as i32 {
+                let on = self.get_pixel(px, py) == target;
+                same.put(px, py, on);
+                rest.put(px, py, on);
+            }
+        }
+        rest.flood_fill(x, y, false);
+        for py in 0..self.height() as i32 {
+            for px in 0..self.width() as i32 {
+                if same.get(px, py) && !rest.get(px, py) {
+                    self.set_pixel(px, py, color);
+                }
+            }
+        }
+    }
+
+    /// video_ram(LedPanel)の点灯ドットの色を設定する
+    ///
+    /// LedPanelとしての次のpresent()で、video_ram全体が塗り直される。
+    pub fn set_pen(&mut self, color: Rgb) {
+        self.pen = color;
+        self.layer.invalidate();
+    }
+
+    /// video_ram(LedPanel)の点灯ドットの色
+    pub fn pen(&self) -> Rgb {
+        self.pen
+    }
+
+    /// video_ram(LedPanel)の消灯ドットの色を設定する
+    ///
+    /// LedPanelとしての次のpresent()で、video_ram全体が塗り直される。
+    pub fn set_paper(&mut self, color: Rgb) {
+        self.paper = color;
+        self.layer.invalidate();
+    }
+
+    /// video_ram(LedPanel)の消灯ドットの色
+    pub fn paper(&self) -> Rgb {
+        self.paper
+    }
+
+    /// 輝度を設定する(0〜MAX_BRIGHTNESS)
+    ///
+    /// 転送中の場合は、転送完了後に反映される。
+    pub fn set_brightness(&mut self, level: u8) -> Result<()> {
+        let level = level.min(MAX_BRIGHTNESS);
+        if level != self.brightness {
+            self.brightness = level;
+            self.dirty = true;
+        }
+        self.present()
+    }
+
+    /// 輝度
+    pub fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    /// フレームバッファの内容を表示する
+    ///
+    /// WS2812は部分的な書き換えができないので、変化があれば全画素を送る。
+    /// 転送中であれば、転送完了後に表示される。(完了を待たずに戻る)
+    pub fn present(&mut self) -> Result<()> {
+        if self.transport.is_pending() {
+            // 転送待ちのフレームは破棄されるので、送り直す
+            self.dirty = true;
+        }
+        if !self.dirty {
+            return Ok(());
+        }
+        self.transport.begin()?;
+        let mut chunk = [Rgb::BLACK; WRITE_CHUNK];
+        let pixels = self.config.pixels();
+        for top in (0..pixels).step_by(WRITE_CHUNK) {
+            let len = WRITE_CHUNK.min(pixels - top);
+            for (i, pixel) in chunk[0..len].iter_mut().enumerate() {
+                let (x, y) = self.config.pixel_position(top + i);
+                *pixel = self.buff[y * self.width() + x].scale(self.brightness);
+            }
+            self.transport.write(&chunk[0..len])?;
+        }
+        self.transport.flush()?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// video_ramの変化したバイトを、文字色・背景色でフレームバッファに塗る
+    fn paint_layer(&mut self) {
+        let width = self.width();
+        for y in 0..self.layer.height() {
+            for col in 0..self.layer.stride() {
+                if !self.layer.is_dirty(col, y) {
+                    continue;
+                }
+                let byte = self.layer.byte(col, y);
+                for bit in 0..8 {
+                    let x = col * 8 + bit;
+                    if x < width {
+                        let on = byte & (0x80 >> bit) != 0;
+                        let color = if on { self.pen } else { self.paper };
+                        self.set_pixel(x as i32, y as i32, color);
+                    }
+                }
+            }
+        }
+        self.layer.clear_dirty();
+    }
+
+    /// 図形をマスクに描き、点灯したドットを指定の色で塗る
+    fn stroke<F: FnOnce(&mut VideoRam)>(&mut self, color: Rgb, draw: F) {
+        let mut mask = self.new_mask();
+        draw(&mut mask);
+        self.paint(&mask, color);
+    }
+
+    /// パネルを覆う図形描画用のVideoRam
+    fn new_mask(&self) -> VideoRam {
+        VideoRam::new(self.width().div_ceil(8) * 8, self.height())
+    }
+
+    /// 座標からバッファのindexを求める
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width() || y as usize >= self.height() {
+            return None;
+        }
+        Some(y as usize * self.width() + x as usize)
+    }
+}
+
+impl<T: PixelTransport> LedPanel for RgbMatrix<T> {
+    fn video_ram(&self) -> &VideoRam {
+        &self.layer
+    }
+
+    fn video_ram_mut(&mut self) -> &mut VideoRam {
+        &mut self.layer
+    }
+
+    /// video_ramの変化した部分をフレームバッファに塗ってから、表示する
+    fn present(&mut self) -> Result<()> {
+        self.paint_layer();
+        RgbMatrix::present(self)
+    }
+
+    fn set_brightness(&mut self, level: u8) -> Result<()> {
+        RgbMatrix::set_brightness(self, level)
+    }
+
+    fn is_busy(&self) -> bool {
+        self.transport.is_busy()
+    }
+
+    fn width(&self) -> usize {
+        RgbMatrix::width(self)
+    }
+
+    fn height(&self) -> usize {
+        RgbMatrix::height(self)
+    }
+}
+
+impl From<(u8, u8, u8)> for Rgb {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Rgb::new(r, g, b)
+    }
+}
+
+/// 画素の色を、0xRRGGBBの形で指定する
+impl From<u32> for Rgb {
+    fn from(rgb: u32) -> Self {
+        Rgb::new((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix_led::Rotation;
+    use crate::transport::RecordingPixels;
+
+    /// 行毎に並んだwidth*heightのパネル
+    fn rows(width: usize, height: usize, wiring: Wiring) -> RgbMatrix<RecordingPixels> {
+        let config = RgbPanelConfig::new(width, height, Scan::Rows, wiring);
+        RgbMatrix::with_config(RecordingPixels::new(), config)
+    }
+
+    #[test]
+    fn pixel_position_mapping() {
+        let config = RgbPanelConfig::new(4, 3, Scan::Rows, Wiring::Progressive);
+        assert_eq!(config.pixel_position(5), (1, 1));
+        let config = RgbPanelConfig::new(4, 3, Scan::Rows, Wiring::Serpentine);
+        assert_eq!(config.pixel_position(4), (3, 1));
+        assert_eq!(config.pixel_position(7), (0, 1));
+        assert_eq!(config.pixel_position(8), (0, 2));
+        let config = RgbPanelConfig::new(4, 3, Scan::Columns, Wiring::Serpentine);
+        assert_eq!(config.pixel_position(3), (1, 2));
+        assert_eq!(config.pixel_position(5), (1, 0));
+        // 標準のパネルは、列毎に折り返す
+        let config = RgbPanelConfig::default();
+        assert_eq!(config.pixel_position(8), (1, 7));
+        assert_eq!(config.pixel_position(15), (1, 0));
+        assert_eq!(config.pixel_position(16), (2, 0));
+        // 逆さまに取り付けたパネル
+        let config = RgbPanelConfig::new(4, 3, Scan::Rows, Wiring::Progressive)
+            .with_orientation(Orientation::rotate(Rotation::Deg180));
+        assert_eq!(config.pixel_position(0), (3, 2));
+        // 縦置き(論理座標は3*4)
+        let config = RgbPanelConfig::new(4, 3, Scan::Columns, Wiring::Serpentine)
+            .with_orientation(Orientation::rotate(Rotation::Deg90));
+        assert_eq!((config.logical_width(), config.logical_height()), (3, 4));
+        let mut seen = [false; 12];
+        for index in 0..config.pixels() {
+            let (x, y) = config.pixel_position(index);
+            assert!(x < 3 && y < 4);
+            assert!(!seen[y * 3 + x]);
+            seen[y * 3 + x] = true;
+        }
+    }
+
+    #[test]
+    fn scale_by_brightness() {
+        assert_eq!(Rgb::WHITE.scale(MAX_BRIGHTNESS), Rgb::WHITE);
+        assert_eq!(Rgb::WHITE.scale(0xFF), Rgb::WHITE);
+        assert_eq!(Rgb::WHITE.scale(0), Rgb::new(15, 15, 15));
+        assert_eq!(Rgb::WHITE.scale(7), Rgb::new(127, 127, 127));
+        assert_eq!(Rgb::new(16, 32, 0).scale(1), Rgb::new(2, 4, 0));
+        assert_eq!(Rgb::from(0x102030), Rgb::new(0x10, 0x20, 0x30));
+    }
+
+    #[test]
+    fn present_scales_pixels() {
+        let mut led = rows(4, 2, Wiring::Serpentine);
+        led.set_pixel(0, 1, Rgb::new(160, 80, 16));
+        led.present().unwrap();
+        let pixels = led.transport().pixels();
+        assert_eq!(pixels.len(), 8);
+        // 2行目は右から左へ並ぶ
+        assert_eq!(pixels[7], Rgb::new(30, 15, 3));
+        let flushed = led.transport().flush_count();
+        // 変化が無ければ送らない
+        led.present().unwrap();
+        assert_eq!(led.transport().flush_count(), flushed);
+        led.set_brightness(MAX_BRIGHTNESS).unwrap();
+        assert_eq!(led.transport().pixels()[7], Rgb::new(160, 80, 16));
+    }
+
+    #[test]
+    fn pen_and_paper_layer() {
+        let mut led = rows(6, 2, Wiring::Progressive);
+        led.set_pen(Rgb::RED);
+        led.video_ram_mut().put(1, 0, true);
+        LedPanel::present(&mut led).unwrap();
+        assert_eq!(led.get_pixel(1, 0), Rgb::RED);
+        assert_eq!(led.get_pixel(0, 0), Rgb::BLACK);
+        // 直接描いたドットは、video_ramが変化しなければ塗り直さない
+        led.set_pixel(4, 1, Rgb::GREEN);
+        LedPanel::present(&mut led).unwrap();
+        assert_eq!(led.get_pixel(4, 1), Rgb::GREEN);
+        // 色の変更は、全体を塗り直す
+        led.set_paper(Rgb::BLUE);
+        LedPanel::present(&mut led).unwrap();
+        assert_eq!(led.get_pixel(1, 0), Rgb::RED);
+        assert_eq!(led.get_pixel(4, 1), Rgb::BLUE);
+        let blue = Rgb::BLUE.scale(DEFAULT_BRIGHTNESS);
+        assert_eq!(
+            led.transport()
+                .pixels()
+                .iter()
+                .filter(|&&p| p == blue)
+                .count(),
+            11
+        );
+    }
+
+    #[test]
+    fn stroke_and_flood_fill() {
+        let mut led = rows(6, 4, Wiring::Progressive);
+        led.draw_rect(0, 0, 6, 4, Rgb::GREEN);
+        led.flood_fill(2, 1, Rgb::RED);
+        let count = |led: &RgbMatrix<RecordingPixels>, color: Rgb| {
+            (0..4)
+                .flat_map(|y| (0..6).map(move |x| (x, y)))
+                .filter(|&(x, y)| led.get_pixel(x, y) == color)
+                .count()
+        };
+        assert_eq!(count(&led, Rgb::GREEN), 6 * 2 + 2 * 2);
+        assert_eq!(count(&led, Rgb::RED), 4 * 2);
+        // 枠の色の領域を塗る
+        led.flood_fill(0, 0, Rgb::BLUE);
+        assert_eq!(count(&led, Rgb::BLUE), 6 * 2 + 2 * 2);
+        led.flood_fill(-1, 0, Rgb::WHITE);
+        assert_eq!(count(&led, Rgb::WHITE), 0);
+        // ビットマップの0のドットは変えない
+        led.draw_bitmap(1, 1, 2, &[0b10], Rgb::YELLOW);
+        assert_eq!(led.get_pixel(1, 1), Rgb::YELLOW);
+        assert_eq!(led.get_pixel(2, 1), Rgb::RED);
+    }
+}
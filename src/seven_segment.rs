@@ -0,0 +1,451 @@
+//! MAX7219による7セグメントLEDの制御
+//!  1チップあたり最大8桁の7セグメントLEDを、chips個チェーンしたもの
+//!
+//! 桁の位置は、左端を0として数える。
+//! 送信順の先頭のチップが左端。チップ内では、digit 0が右端となる。
+//!
+//! 各桁のデータは、MAX7219の桁レジスタの値そのもの。
+//!   Raw(デコード無し):   D7:DP D6:A D5:B D4:C D3:D D2:E D1:F D0:G
+//!   CodeB(BCDデコード):  D7:DP 下位4bit:Code-B (0〜9 - E H L P 空白)
+//!
+//! present()で、デコード・スキャン桁・輝度の各レジスタと全桁を1フレームとして送る。
+//! 1フレームで全ての状態を送るので、転送待ちのフレームが破棄されても問題無い。
+
+use core::fmt;
+
+use crate::matrix_led::{Error, Result, MAX_BRIGHTNESS, MAX_MODULES};
+use crate::transport::Transport;
+
+/// 1チップあたりの最大桁数
+pub const DIGITS_PER_CHIP: usize = 8;
+
+/// 全体の最大桁数
+pub const MAX_DIGITS: usize = MAX_MODULES * DIGITS_PER_CHIP;
+
+/// 小数点のビット
+pub const SEG_DP: u8 = 0x80;
+
+/// MAX7219 レジスタアドレス
+const REG_DECODE: u16 = 0x0900;
+const REG_INTENSITY: u16 = 0x0A00;
+const REG_SCAN_LIMIT: u16 = 0x0B00;
+const REG_SHUTDOWN: u16 = 0x0C00;
+const REG_TEST: u16 = 0x0F00;
+
+/// Code-Bの空白
+const CODE_B_BLANK: u8 = 0x0F;
+
+/// 輝度の初期値
+const DEFAULT_BRIGHTNESS: u8 = 0x02;
+
+/// デコードモード
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Decode {
+    /// デコード無し(セグメントを直接指定)
+    Raw,
+    /// Code-B(BCD)デコード
+    CodeB,
+}
+
+impl Decode {
+    /// デコードレジスタの値
+    fn register(self) -> u16 {
+        match self {
+            Decode::Raw => 0x00,
+            Decode::CodeB => 0xFF,
+        }
+    }
+
+    /// 空白の桁データ
+    fn blank(self) -> u8 {
+        match self {
+            Decode::Raw => 0x00,
+            Decode::CodeB => CODE_B_BLANK,
+        }
+    }
+
+    /// 文字を桁データに変換する。表示できない文字はNone
+    pub fn encode(self, c: char) -> Option<u8> {
+        match self {
+            Decode::Raw => raw_segments(c),
+            Decode::CodeB => code_b(c),
+        }
+    }
+}
+
+/// 文字のセグメントパターン(デコード無し)
+fn raw_segments(c: char) -> Option<u8> {
+    let seg = match c {
+        '0' | 'O' => 0x7E,
+        '1' => 0x30,
+        '2' => 0x6D,
+        '3' => 0x79,
+        '4' => 0x33,
+        '5' | 'S' | 's' => 0x5B,
+        '6' => 0x5F,
+        '7' => 0x70,
+        '8' => 0x7F,
+        '9' => 0x7B,
+        'A' | 'a' => 0x77,
+        'B' | 'b' => 0x1F,
+        'C' => 0x4E,
+        'c' => 0x0D,
+        'D' | 'd' => 0x3D,
+        'E' | 'e' => 0x4F,
+        'F' | 'f' => 0x47,
+        'G' | 'g' => 0x5E,
+        'H' => 0x37,
+        'h' => 0x17,
+        'I' | 'i' => 0x06,
+        'J' | 'j' => 0x3C,
+        'L' | 'l' => 0x0E,
+        'N' | 'n' => 0x15,
+        'o' => 0x1D,
+        'P' | 'p' => 0x67,
+        'Q' | 'q' => 0x73,
+        'R' | 'r' => 0x05,
+        'T' | 't' => 0x0F,
+        'U' => 0x3E,
+        'u' => 0x1C,
+        'Y' | 'y' => 0x3B,
+        '-' => 0x01,
+        '_' => 0x08,
+        '=' => 0x09,
+        ' ' => 0x00,
+        _ => return None,
+    };
+    Some(seg)
+}
+
+/// 文字のCode-B
+fn code_b(c: char) -> Option<u8> {
+    let code = match c {
+        '0'..='9' => c as u8 - b'0',
+        '-' => 0x0A,
+        'E' | 'e' => 0x0B,
+        'H' | 'h' => 0x0C,
+        'L' | 'l' => 0x0D,
+        'P' | 'p' => 0x0E,
+        ' ' => CODE_B_BLANK,
+        _ => return None,
+    };
+    Some(code)
+}
+
+/// 7セグメントLEDの制御
+pub struct SevenSegment<T: Transport> {
+    transport: T,
+    chips: usize,
+    digits: usize, // 1チップあたりの桁数(スキャン桁数)
+    decode: Decode,
+    brightness: u8,
+    data: [u8; MAX_DIGITS], // 各桁のデータ(左端から)
+    line: [u8; MAX_DIGITS], // fmt::Writeで組み立て中の行
+    cursor: usize,          // lineの書き込み位置
+    dp_free: bool,          // 直前の桁の小数点が未使用か
+}
+
+impl<T: Transport> SevenSegment<T> {
+    /// chips個のチップ、1チップあたりdigits桁で初期化する
+    ///
+    /// # Panics
+    /// チップ数が0、もしくはMAX_MODULESを超える場合。
+    /// 桁数が0、もしくはDIGITS_PER_CHIPを超える場合
+    pub fn new(transport: T, chips: usize, digits: usize, decode: Decode) -> SevenSegment<T> {
+        assert!(chips > 0 && chips <= MAX_MODULES);
+        assert!(digits > 0 && digits <= DIGITS_PER_CHIP);
+        let mut seg = SevenSegment {
+            transport,
+            chips,
+            digits,
+            decode,
+            brightness: DEFAULT_BRIGHTNESS,
+            data: [decode.blank(); MAX_DIGITS],
+            line: [decode.blank(); MAX_DIGITS],
+            cursor: 0,
+            dp_free: false,
+        };
+        seg.init_seven_segment();
+        seg
+    }
+
+    /// 送信路への参照
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// 全体の桁数
+    pub fn digit_count(&self) -> usize {
+        self.chips * self.digits
+    }
+
+    /// デコードモード
+    pub fn decode(&self) -> Decode {
+        self.decode
+    }
+
+    /// デコードモードを変更する
+    ///
+    /// 各桁のデータの意味が変わるので、全桁を空白にする。
+    pub fn set_decode(&mut self, decode: Decode) {
+        self.decode = decode;
+        self.clear();
+    }
+
+    /// 1チップあたりの桁数(スキャン桁数)を変更する(1〜DIGITS_PER_CHIP)
+    pub fn set_scan_limit(&mut self, digits: usize) -> Result<()> {
+        if digits == 0 || digits > DIGITS_PER_CHIP {
+            return Err(Error::OutOfRange);
+        }
+        self.digits = digits;
+        self.clear();
+        Ok(())
+    }
+
+    /// 輝度を設定する(0〜MAX_BRIGHTNESS)
+    ///
+    /// 次のpresent()で反映される。
+    pub fn set_brightness(&mut self, level: u8) {
+        self.brightness = level.min(MAX_BRIGHTNESS);
+    }
+
+    /// 全桁を空白にする
+    pub fn clear(&mut self) {
+        let blank = self.decode.blank();
+        for d in &mut self.data[..] {
+            *d = blank;
+        }
+    }
+
+    /// 指定桁のデータを、そのまま設定する
+    ///
+    /// Rawではセグメントパターン、CodeBではCode-Bとなる。(D7は小数点)
+    pub fn set_segments(&mut self, pos: usize, data: u8) -> Result<()> {
+        if pos >= self.digit_count() {
+            return Err(Error::OutOfRange);
+        }
+        self.data[pos] = data;
+        Ok(())
+    }
+
+    /// 指定桁のデータ
+    pub fn segments(&self, pos: usize) -> u8 {
+        self.data[pos]
+    }
+
+    /// 指定桁に文字を表示する
+    ///
+    /// 現在のデコードモードで表示できない文字は、空白とし、falseを返す。
+    /// 小数点の状態は変えない。
+    pub fn set_char(&mut self, pos: usize, c: char) -> Result<bool> {
+        if pos >= self.digit_count() {
+            return Err(Error::OutOfRange);
+        }
+        let (data, ok) = match self.decode.encode(c) {
+            Some(data) => (data, true),
+            None => (self.decode.blank(), false),
+        };
+        self.data[pos] = (self.data[pos] & SEG_DP) | data;
+        Ok(ok)
+    }
+
+    /// 指定桁の小数点を点灯(true)/消灯(false)する
+    pub fn set_dp(&mut self, pos: usize, on: bool) -> Result<()> {
+        if pos >= self.digit_count() {
+            return Err(Error::OutOfRange);
+        }
+        if on {
+            self.data[pos] |= SEG_DP;
+        } else {
+            self.data[pos] &= !SEG_DP;
+        }
+        Ok(())
+    }
+
+    /// 全桁を表示する
+    ///
+    /// 転送中であれば、転送完了後に表示される。(完了を待たずに戻る)
+    pub fn present(&mut self) -> Result<()> {
+        self.transport.begin()?;
+        self.send_all(REG_DECODE | self.decode.register())?;
+        self.send_all(REG_SCAN_LIMIT | (self.digits - 1) as u16)?;
+        self.send_all(REG_INTENSITY | self.brightness as u16)?;
+        for digit in 0..self.digits {
+            self.send_digit(digit)?;
+        }
+        self.transport.flush()
+    }
+
+    /// 各チップの同じ桁をまとめて1パケットとして送る
+    /// # 引数
+    ///     digit:  チップ内の桁番号(0が右端)
+    fn send_digit(&mut self, digit: usize) -> Result<()> {
+        let digi_code: u16 = ((digit + 1) << 8) as u16;
+        let mut dat = [0u16; MAX_MODULES];
+        for (chip, word) in dat[0..self.chips].iter_mut().enumerate() {
+            let pos = chip * self.digits + (self.digits - 1 - digit);
+            *word = digi_code | self.data[pos] as u16;
+        }
+        self.send_packet(&dat[0..self.chips])
+    }
+
+    /// 全チップに同じワードを送る
+    fn send_all(&mut self, word: u16) -> Result<()> {
+        self.send_packet(&[word; MAX_MODULES][0..self.chips])
+    }
+
+    /// 1パケット(CS有効〜無効)を送る
+    fn send_packet(&mut self, dat: &[u16]) -> Result<()> {
+        self.transport.select()?;
+        self.transport.write(dat)?;
+        self.transport.deselect()
+    }
+
+    /// 7セグメントLED 初期化
+    fn init_seven_segment(&mut self) {
+        while self.transport.begin().is_err() {}
+        self.send_all(REG_TEST).unwrap(); // テストモード解除
+        self.send_all(REG_SHUTDOWN | 0x01).unwrap(); // シャットダウンモード　解除
+        self.transport.flush().unwrap();
+        self.present().unwrap();
+    }
+}
+
+/// 文字列を左詰めで表示する
+///
+/// '\n'までを1行とし、'\n'で表示する。桁数を超えた分は捨てる。
+/// '.'は、直前の桁の小数点とする。(直前の桁の小数点が使用済みなら、空白の桁に点ける)
+/// 表示できない文字は、空白とする。
+impl<T: Transport> fmt::Write for SevenSegment<T> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let blank = self.decode.blank();
+        for c in s.chars() {
+            match c {
+                '\n' => {
+                    self.data = self.line;
+                    for d in &mut self.line[..] {
+                        *d = blank;
+                    }
+                    self.cursor = 0;
+                    self.dp_free = false;
+                    self.present().map_err(|_| fmt::Error)?;
+                }
+                '.' if self.dp_free => {
+                    self.line[self.cursor - 1] |= SEG_DP;
+                    self.dp_free = false;
+                }
+                cc if cc.is_ascii_control() => {}
+                cc => {
+                    if self.cursor < self.digit_count() {
+                        self.line[self.cursor] = if cc == '.' {
+                            blank | SEG_DP
+                        } else {
+                            self.decode.encode(cc).unwrap_or(blank)
+                        };
+                        self.cursor += 1;
+                        self.dp_free = cc != '.';
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::RecordingTransport;
+    use core::fmt::Write;
+
+    /// 送信したパケットの一覧
+    fn packets(seg: &SevenSegment<RecordingTransport>) -> Vec<Vec<u16>> {
+        seg.transport().packets().map(|p| p.to_vec()).collect()
+    }
+
+    #[test]
+    fn init_sequence() {
+        let seg = SevenSegment::new(RecordingTransport::new(), 2, 8, Decode::CodeB);
+        let sent = packets(&seg);
+        assert_eq!(sent.len(), 2 + 3 + 8);
+        assert_eq!(sent[0], [0x0F00; 2]);
+        assert_eq!(sent[1], [0x0C01; 2]);
+        assert_eq!(sent[2], [0x09FF; 2]);
+        assert_eq!(sent[3], [0x0B07; 2]);
+        assert_eq!(sent[4], [0x0A02; 2]);
+        assert_eq!(sent[5], [0x010F; 2]);
+        assert_eq!(seg.transport().flush_count(), 2);
+    }
+
+    #[test]
+    fn code_b_digits() {
+        let mut seg = SevenSegment::new(RecordingTransport::new(), 2, 4, Decode::CodeB);
+        let start = seg.transport().packets().count();
+        writeln!(seg, "12.3-E").unwrap();
+        let sent = &packets(&seg)[start..];
+        assert_eq!(sent[0], [0x09FF; 2]);
+        assert_eq!(sent[1], [0x0B03; 2]);
+        // チップ0が左端。チップ内では、digit 0(レジスタ1)が右端
+        assert_eq!(sent[3], [0x010A, 0x010F]);
+        assert_eq!(sent[4], [0x0203, 0x020F]);
+        assert_eq!(sent[5], [0x0382, 0x030F]);
+        assert_eq!(sent[6], [0x0401, 0x040B]);
+        assert_eq!(sent.len(), 3 + 4);
+    }
+
+    #[test]
+    fn decimal_point_folding() {
+        let mut seg = SevenSegment::new(RecordingTransport::new(), 1, 8, Decode::CodeB);
+        writeln!(seg, ".1..2").unwrap();
+        // 先頭の'.'と、小数点が使用済みの'.'は、空白の桁に点ける
+        assert_eq!(seg.segments(0), SEG_DP | CODE_B_BLANK);
+        assert_eq!(seg.segments(1), SEG_DP | 1);
+        assert_eq!(seg.segments(2), SEG_DP | CODE_B_BLANK);
+        assert_eq!(seg.segments(3), 2);
+        assert_eq!(seg.segments(4), CODE_B_BLANK);
+        // 桁数を超えた分は捨てる
+        writeln!(seg, "123456789.").unwrap();
+        assert_eq!(seg.segments(7), SEG_DP | 8);
+    }
+
+    #[test]
+    fn raw_segments_and_dp() {
+        let mut seg = SevenSegment::new(RecordingTransport::new(), 1, 8, Decode::Raw);
+        assert_eq!(seg.set_char(0, 'A'), Ok(true));
+        seg.set_dp(0, true).unwrap();
+        assert_eq!(seg.segments(0), 0xF7);
+        // 小数点の状態は変えない
+        assert_eq!(seg.set_char(0, '1'), Ok(true));
+        assert_eq!(seg.segments(0), 0xB0);
+        assert_eq!(seg.set_char(0, '#'), Ok(false));
+        assert_eq!(seg.segments(0), SEG_DP);
+        seg.set_segments(7, 0x49).unwrap();
+        assert_eq!(seg.set_segments(8, 0x49), Err(Error::OutOfRange));
+        assert_eq!(seg.set_dp(8, true), Err(Error::OutOfRange));
+        let start = seg.transport().packets().count();
+        seg.present().unwrap();
+        let sent = &packets(&seg)[start..];
+        assert_eq!(sent[0], [0x0900]);
+        assert_eq!(sent[3], [0x0149]);
+        assert_eq!(sent[10], [0x0880]);
+    }
+
+    #[test]
+    fn scan_limit() {
+        let mut seg = SevenSegment::new(RecordingTransport::new(), 3, 8, Decode::CodeB);
+        assert_eq!(seg.set_scan_limit(0), Err(Error::OutOfRange));
+        assert_eq!(
+            seg.set_scan_limit(DIGITS_PER_CHIP + 1),
+            Err(Error::OutOfRange)
+        );
+        seg.set_scan_limit(2).unwrap();
+        assert_eq!(seg.digit_count(), 6);
+        seg.set_brightness(0xFF);
+        let start = seg.transport().packets().count();
+        seg.present().unwrap();
+        let sent = &packets(&seg)[start..];
+        assert_eq!(sent.len(), 3 + 2);
+        assert_eq!(sent[1], [0x0B01; 3]);
+        assert_eq!(sent[2], [0x0A0F; 3]);
+    }
+}
@@ -0,0 +1,415 @@
+//! 表示内容のスナップショット(デバッグ用)
+//!
+//! video_ramを、ASCIIアート、またはPBM(P1 テキスト形式)として書き出す。
+//! 書き出し先はfmt::Writeなので、semihosting(hio::hstdout)・ITM・UART等、
+//! fmt::Writeを実装したものであれば何でも良い。
+//!
+//! MAX7219へ実際に送ったワード列は、TapTransportで送信路を包んで記録し、
+//! write_words()でテキストとして書き出す。
+//! 書き出したワード列は、Max7219Decoderで画像に戻せる。(ホスト上でも使える)
+//!
+//! Matrixは変化した行のみを送るので、1フレームのワード列で全体を再現するには、
+//! present_full()で送ったフレームを書き出すこと。
+//! (Max7219Decoderは状態を持つので、初期化からの全フレームを順に与えても良い)
+
+use core::fmt;
+use core::mem;
+
+use crate::matrix_led::{Error, PanelConfig, Result, MAX_MODULES};
+use crate::transport::{RecordedPackets, RecordingTransport, Transport};
+use crate::video_ram::VideoRam;
+
+/// ASCIIアートの点灯ドット
+pub const ASCII_ON: char = '#';
+/// ASCIIアートの消灯ドット
+pub const ASCII_OFF: char = '.';
+
+/// MAX7219 レジスタアドレス
+const REG_DECODE: u8 = 0x09;
+const REG_SCAN_LIMIT: u8 = 0x0B;
+const REG_SHUTDOWN: u8 = 0x0C;
+const REG_TEST: u8 = 0x0F;
+
+/// video_ramを、1行1ラインのASCIIアートとして書き出す
+pub fn write_ascii<W: fmt::Write>(vram: &VideoRam, out: &mut W) -> fmt::Result {
+    for y in 0..vram.height() as i32 {
+        for x in 0..vram.width() as i32 {
+            out.write_char(if vram.get(x, y) { ASCII_ON } else { ASCII_OFF })?;
+        }
+        out.write_char('\n')?;
+    }
+    Ok(())
+}
+
+/// video_ramを、PBM(P1 テキスト形式)として書き出す
+pub fn write_pbm<W: fmt::Write>(vram: &VideoRam, out: &mut W) -> fmt::Result {
+    writeln!(out, "P1")?;
+    writeln!(out, "{} {}", vram.width(), vram.height())?;
+    for y in 0..vram.height() as i32 {
+        for x in 0..vram.width() as i32 {
+            if x > 0 {
+                out.write_char(' ')?;
+            }
+            out.write_char(if vram.get(x, y) { '1' } else { '0' })?;
+        }
+        out.write_char('\n')?;
+    }
+    Ok(())
+}
+
+/// ワード列を、1行1パケットの16進数(4桁、空白区切り)として書き出す
+pub fn write_words<'a, W, I>(packets: I, out: &mut W) -> fmt::Result
+where
+    W: fmt::Write,
+    I: IntoIterator<Item = &'a [u16]>,
+{
+    for packet in packets {
+        for (i, word) in packet.iter().enumerate() {
+            if i > 0 {
+                out.write_char(' ')?;
+            }
+            write!(out, "{:04X}", word)?;
+        }
+        out.write_char('\n')?;
+    }
+    Ok(())
+}
+
+/// 送信路を包み、最後に送信を開始したフレームのワード列を記録するTransport
+///
+/// 送信は、包んだ送信路へそのまま渡す。
+/// flush()したフレームは、包んだ送信路が転送待ちとしている間は記録に含めず、
+/// 転送待ちでなくなった(送信を開始した)時点で、最後のフレームとする。
+/// 転送待ちのまま次のbegin()で破棄されたフレームは、記録に残らない。
+pub struct TapTransport<T: Transport> {
+    inner: T,
+    assembling: RecordingTransport, // 組み立て中のフレーム
+    queued: RecordingTransport,     // flush()済みのフレーム
+    has_queued: bool,               // queuedが有効か
+    sent: RecordingTransport,       // 送信を開始したフレーム
+}
+
+impl<T: Transport> TapTransport<T> {
+    pub fn new(inner: T) -> Self {
+        TapTransport {
+            inner,
+            assembling: RecordingTransport::new(),
+            queued: RecordingTransport::new(),
+            has_queued: false,
+            sent: RecordingTransport::new(),
+        }
+    }
+
+    /// 包んだ送信路への参照
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// 最後に送信を開始したフレームの、パケット毎のワード列
+    pub fn packets(&self) -> RecordedPackets<'_> {
+        if self.is_queued_started() {
+            self.queued.packets()
+        } else {
+            self.sent.packets()
+        }
+    }
+
+    /// flush()済みのフレームが、送信を開始したか
+    fn is_queued_started(&self) -> bool {
+        self.has_queued && !self.inner.is_pending()
+    }
+
+    /// 送信を開始したflush()済みのフレームを、最後のフレームとする
+    fn settle(&mut self) {
+        if self.is_queued_started() {
+            mem::swap(&mut self.queued, &mut self.sent);
+            self.has_queued = false;
+        }
+    }
+
+    /// 最後のフレームを、write_words()の形式で書き出す
+    pub fn write_words<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        write_words(self.packets(), out)
+    }
+}
+
+impl<T: Transport> Transport for TapTransport<T> {
+    /// 転送待ちのフレームは、包んだ送信路が破棄するので、記録からも除く
+    fn begin(&mut self) -> Result<()> {
+        self.settle();
+        self.inner.begin()?;
+        self.has_queued = false;
+        self.assembling.clear();
+        Ok(())
+    }
+
+    fn select(&mut self) -> Result<()> {
+        self.inner.select()?;
+        self.assembling.select()
+    }
+
+    /// 記録できない分は捨てる(送信は続ける)
+    fn write(&mut self, words: &[u16]) -> Result<()> {
+        self.inner.write(words)?;
+        let _ = self.assembling.write(words);
+        Ok(())
+    }
+
+    fn deselect(&mut self) -> Result<()> {
+        self.inner.deselect()?;
+        let _ = self.assembling.deselect();
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()?;
+        self.settle();
+        mem::swap(&mut self.assembling, &mut self.queued);
+        self.has_queued = true;
+        self.settle();
+        Ok(())
+    }
+
+    fn is_busy(&self) -> bool {
+        self.inner.is_busy()
+    }
+
+    fn is_pending(&self) -> bool {
+        self.inner.is_pending()
+    }
+}
+
+/// MAX7219チェーンの動作を模擬し、ワード列から表示内容を再現する
+///
+/// 各モジュールのレジスタを保持し、パケット毎に更新する。
+/// パケットの先頭のワードは、チェーンの終端のモジュールに入る。(Matrixと同じ)
+/// デコードモードが有効な桁は、正しく再現できない。(Matrixでは使わない)
+pub struct Max7219Decoder {
+    config: PanelConfig,
+    regs: [[u8; 16]; MAX_MODULES], // 各モジュールのレジスタ(送信順)
+}
+
+impl Max7219Decoder {
+    /// 指定のパネル構成で、電源投入直後の状態(シャットダウン)とする
+    ///
+    /// # Panics
+    /// モジュール数が0、もしくはMAX_MODULESを超える場合
+    pub fn new(config: PanelConfig) -> Max7219Decoder {
+        assert!(config.modules() > 0 && config.modules() <= MAX_MODULES);
+        Max7219Decoder {
+            config,
+            regs: [[0; 16]; MAX_MODULES],
+        }
+    }
+
+    /// 1パケット(CS有効〜無効)のワード列を与える
+    pub fn feed_packet(&mut self, words: &[u16]) {
+        let modules = self.config.modules();
+        // 後に送ったワードほど、マイコンに近いモジュールに残る
+        let skip = words.len().saturating_sub(modules);
+        let top = modules - (words.len() - skip);
+        for (i, word) in words[skip..].iter().enumerate() {
+            let reg = ((word >> 8) & 0x0F) as usize;
+            if reg != 0 {
+                self.regs[top + i][reg] = *word as u8;
+            }
+        }
+    }
+
+    /// write_words()の形式のテキストを与える
+    ///
+    /// 空行と、'#'で始まる行は無視する。
+    /// 16進数として読めない語があれば、その行以降を無視してエラーとする。
+    pub fn feed_text(&mut self, text: &str) -> Result<()> {
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut words = [0u16; MAX_MODULES];
+            let mut len = 0;
+            for token in line.split_whitespace() {
+                if len >= MAX_MODULES {
+                    return Err(Error::InvalidFormat);
+                }
+                words[len] = u16::from_str_radix(token, 16).map_err(|_| Error::InvalidFormat)?;
+                len += 1;
+            }
+            self.feed_packet(&words[0..len]);
+        }
+        Ok(())
+    }
+
+    /// 送信順index番目のモジュールのレジスタ
+    pub fn register(&self, index: usize, reg: u8) -> u8 {
+        self.regs[index][(reg & 0x0F) as usize]
+    }
+
+    /// 現在の表示内容を、論理座標のvideo_ramとして再現する
+    ///
+    /// シャットダウン中のモジュールは消灯、テストモードのモジュールは全点灯とする。
+    pub fn render(&self) -> VideoRam {
+        let mut vram = VideoRam::new(self.config.width(), self.config.height());
+        for index in 0..self.config.modules() {
+            let regs = &self.regs[index];
+            let (col, row) = self.config.module_position(index);
+            let test = regs[REG_TEST as usize] & 0x01 != 0;
+            let active = regs[REG_SHUTDOWN as usize] & 0x01 != 0;
+            let scan = (regs[REG_SCAN_LIMIT as usize] & 0x07) as usize;
+            for line in 0..8 {
+                let pat = if test {
+                    0xFF
+                } else if !active || line > scan || regs[REG_DECODE as usize] & (1 << line) != 0 {
+                    0x00
+                } else {
+                    regs[line + 1]
+                };
+                for bit in 0..8 {
+                    let (x, y) = self.config.logical_position(col, row, line, bit);
+                    vram.put(x as i32, y as i32, pat & (0x80 >> bit) != 0);
+                }
+            }
+        }
+        vram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix_led::Matrix;
+
+    /// 転送中・転送待ちを模擬する送信路
+    ///   flush()は、転送中なら転送待ちとなる。finish()で転送を終える
+    struct QueueTransport {
+        busy: bool,
+        pending: bool,
+    }
+
+    impl QueueTransport {
+        fn new() -> Self {
+            QueueTransport {
+                busy: false,
+                pending: false,
+            }
+        }
+
+        /// 転送中のフレームを終え、転送待ちがあれば開始する
+        fn finish(&mut self) {
+            self.busy = self.pending;
+            self.pending = false;
+        }
+    }
+
+    impl Transport for QueueTransport {
+        fn begin(&mut self) -> Result<()> {
+            self.pending = false;
+            Ok(())
+        }
+
+        fn select(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn write(&mut self, _words: &[u16]) -> Result<()> {
+            Ok(())
+        }
+
+        fn deselect(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            if self.busy {
+                self.pending = true;
+            } else {
+                self.busy = true;
+            }
+            Ok(())
+        }
+
+        fn is_busy(&self) -> bool {
+            self.busy
+        }
+
+        fn is_pending(&self) -> bool {
+            self.pending
+        }
+    }
+
+    /// 1ワード1パケットのフレームを送る
+    fn send(tap: &mut TapTransport<QueueTransport>, word: u16) {
+        tap.begin().unwrap();
+        tap.select().unwrap();
+        tap.write(&[word]).unwrap();
+        tap.deselect().unwrap();
+        tap.flush().unwrap();
+    }
+
+    fn last(tap: &TapTransport<QueueTransport>) -> Vec<Vec<u16>> {
+        tap.packets().map(|p| p.to_vec()).collect()
+    }
+
+    #[test]
+    fn tap_records_started_frame() {
+        let mut tap = TapTransport::new(QueueTransport::new());
+        assert!(last(&tap).is_empty());
+        send(&mut tap, 0x0101);
+        assert_eq!(last(&tap), vec![vec![0x0101]]);
+        // 転送中に送ったフレームは、開始するまで記録に含めない
+        send(&mut tap, 0x0202);
+        assert_eq!(last(&tap), vec![vec![0x0101]]);
+        tap.inner.finish();
+        assert_eq!(last(&tap), vec![vec![0x0202]]);
+    }
+
+    #[test]
+    fn tap_drops_discarded_frame() {
+        let mut tap = TapTransport::new(QueueTransport::new());
+        send(&mut tap, 0x0101);
+        send(&mut tap, 0x0202); // 転送待ちのまま破棄される
+        send(&mut tap, 0x0303);
+        assert_eq!(last(&tap), vec![vec![0x0101]]);
+        tap.inner.finish();
+        assert_eq!(last(&tap), vec![vec![0x0303]]);
+    }
+
+    #[test]
+    fn decoder_renders_sent_frame() {
+        let config = PanelConfig::default();
+        let mut m = Matrix::with_config(TapTransport::new(RecordingTransport::new()), config);
+        m.video_ram_mut().put(0, 0, true);
+        m.video_ram_mut().put(9, 3, true);
+        m.video_ram_mut().put(31, 7, true);
+        m.present_full().unwrap();
+        let mut text = String::new();
+        m.transport().write_words(&mut text).unwrap();
+
+        let mut decoder = Max7219Decoder::new(config);
+        decoder.feed_text(&text).unwrap();
+        let vram = decoder.render();
+        for y in 0..8 {
+            for x in 0..32 {
+                assert_eq!(vram.get(x, y), m.video_ram().get(x, y), "({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn decoder_rejects_bad_text() {
+        let mut decoder = Max7219Decoder::new(PanelConfig::default());
+        assert_eq!(decoder.feed_text("# comment\n\n0C01 0C01"), Ok(()));
+        assert_eq!(decoder.register(0, 0x0C), 0x00);
+        assert_eq!(decoder.register(3, 0x0C), 0x01);
+        assert_eq!(decoder.feed_text("0C01 XYZ"), Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn ascii_art() {
+        let mut vram = VideoRam::new(8, 2);
+        vram.put(1, 0, true);
+        let mut text = String::new();
+        write_ascii(&vram, &mut text).unwrap();
+        assert_eq!(text, ".#......\n........\n");
+    }
+}
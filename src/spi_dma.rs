@@ -0,0 +1,1015 @@
+//! SPI + DMA2 による Matrix LED への送信路
+//!  使用するSPI・CS(LOAD)端子・DMAチャンネルは、SpiDmaConfigで指定する。
+//!  (標準は SPI1 / PA4 / チャンネルA)
+//!
+//! TIM1の1周期を1スロットとし、各スロットで
+//!   CS比較:   CS(LOAD)ピンの操作 (DMA2 → GPIOx_BSRR)
+//!   ワード比較: 16bitワードの送信  (DMA2 → SPIx_DR)
+//! の順にDMA転送を行う。CSの立ち上がり(データ確定)もDMAで行うので、
+//! 1フレームを1回のDMA設定で送信でき、割込みはフレーム終了時の1回のみとなる。
+//!
+//! DMAチャンネルは2つあり、別々のSPIを使えば2本のチェーンを独立して駆動できる。
+//!   チャンネルA: CC4(CS) → Stream4 ch6 / CC1(ワード) → Stream1 ch6
+//!   チャンネルB: CC3(CS) → Stream6 ch6 / CC2(ワード) → Stream2 ch6
+//!                (チャンネルAから半スロットずらして動作する)
+//! TIM1は、いずれかのチャンネルが転送中の間、動作し続ける。
+//! TIM1の動作中に送信を始めるチャンネルは、ワード比較の割込み(TIM1_CC)を1回だけ使い、
+//! その割込みでDMA要求を有効にする。(割込み内で待たない)
+//!
+//! 1パケット(チェーン長のワード)は、次のスロットに展開される。
+//!   [ワード0 + CS LO] [ワード1] ... [ワードn-1] [空ワード + CS HI]
+//! 最後のスロットでCSを立ち上げてデータを確定させる。
+//! 同じスロットの空ワードはCS HIの間に送られるが、MAX7219は
+//! CSの状態によらずシフトするので、次のパケットで押し出されて影響しない。
+//!
+//! DMAバッファはチャンネル毎に2面持ち、一方を転送中に、もう一方へ次のフレームを組み立てる。
+//! 転送中にflush()されたフレームは保留され、転送完了の割込みで送信を開始する。
+//! 保留中のフレームは、次のbegin()で破棄され、新しいフレームに置き換わる。
+//!
+//! DMAのエラーは割込みで記録し、次のbegin()で一度だけ返す。
+
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use stm32f4::stm32f401;
+use stm32f4::stm32f401::interrupt;
+
+use crate::matrix_led::{Error, Result, MAX_MODULES};
+use crate::transport::Transport;
+
+/// 1スロットのTIM1カウント数 48MHz/192 = 4μs
+///   SPI 12MHzで1ワード(16bit)の送信に約1.3μs
+const SLOT_PERIOD: u16 = 192;
+/// CS(LOAD)を操作するタイミング
+const CS_COMPARE: u16 = 2;
+/// ワードをSPIに書き込むタイミング
+///   CS操作の完了後、かつ前スロットのワードの送信が終わっていること
+const WORD_COMPARE: u16 = 16;
+
+/// CSを操作しないBSRR値
+const CS_KEEP: u32 = 0;
+
+/// 使用するSPI
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SpiPort {
+    /// SPI1 SCK:PA5 MOSI:PA7
+    Spi1,
+    /// SPI2 SCK:PB13 MOSI:PB15
+    Spi2,
+    /// SPI3 SCK:PC10 MOSI:PC12
+    Spi3,
+}
+
+/// GPIOのポート
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GpioPort {
+    A,
+    B,
+    C,
+    D,
+    E,
+    /// PH0・PH1のみ
+    H,
+}
+
+impl GpioPort {
+    /// ポートにある端子の数(STM32F401)
+    pub fn pins(self) -> u8 {
+        match self {
+            GpioPort::H => 2,
+            _ => 16,
+        }
+    }
+}
+
+/// CS(LOAD)端子
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CsPin {
+    pub port: GpioPort,
+    pub pin: u8, // 0〜15(ポートHは0〜1)
+}
+
+impl CsPin {
+    pub const fn new(port: GpioPort, pin: u8) -> CsPin {
+        CsPin { port, pin }
+    }
+
+    /// ポートに実在する端子か
+    pub fn is_valid(&self) -> bool {
+        self.pin < self.port.pins()
+    }
+
+    /// CSを通信有効(LO)にするBSRR値
+    fn low(&self) -> u32 {
+        1 << (16 + self.pin as u32)
+    }
+
+    /// CSを通信無効(HI)にするBSRR値
+    fn high(&self) -> u32 {
+        1 << self.pin as u32
+    }
+}
+
+/// 使用するDMAチャンネル(DMA2ストリームとTIM1比較チャンネルの組)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DmaChannel {
+    /// ワード: Stream1(TIM1_CH1) CS: Stream4(TIM1_CH4)
+    A,
+    /// ワード: Stream2(TIM1_CH2) CS: Stream6(TIM1_CH3)
+    B,
+}
+
+impl DmaChannel {
+    /// DMA_STATE・DMA_BUFFの番号
+    fn index(self) -> usize {
+        match self {
+            DmaChannel::A => 0,
+            DmaChannel::B => 1,
+        }
+    }
+
+    /// ワード送信のストリーム番号
+    fn word_stream(self) -> usize {
+        match self {
+            DmaChannel::A => 1,
+            DmaChannel::B => 2,
+        }
+    }
+
+    /// CS制御のストリーム番号
+    fn cs_stream(self) -> usize {
+        match self {
+            DmaChannel::A => 4,
+            DmaChannel::B => 6,
+        }
+    }
+
+    /// スロット内の動作タイミングのずれ
+    fn offset(self) -> u16 {
+        match self {
+            DmaChannel::A => 0,
+            DmaChannel::B => SLOT_PERIOD / 2,
+        }
+    }
+
+    fn cs_compare(self) -> u16 {
+        CS_COMPARE + self.offset()
+    }
+
+    fn word_compare(self) -> u16 {
+        WORD_COMPARE + self.offset()
+    }
+}
+
+/// 送信路の構成
+///
+/// 2本のチェーンを駆動する場合は、チャンネル・SPI・CS端子を、それぞれ別にすること。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SpiDmaConfig {
+    pub spi: SpiPort,
+    pub cs: CsPin,
+    pub channel: DmaChannel,
+}
+
+impl SpiDmaConfig {
+    pub const fn new(spi: SpiPort, cs: CsPin, channel: DmaChannel) -> SpiDmaConfig {
+        SpiDmaConfig { spi, cs, channel }
+    }
+}
+
+impl Default for SpiDmaConfig {
+    /// SPI1 / CS:PA4 / チャンネルA
+    fn default() -> Self {
+        Self::new(SpiPort::Spi1, CsPin::new(GpioPort::A, 4), DmaChannel::A)
+    }
+}
+
+/// CS端子を通信無効(HI)とし、汎用出力(プッシュプル・高速)にする
+macro_rules! cs_output {
+    ($gpio:expr, $cs:expr) => {{
+        let pin = $cs.pin as u32;
+        $gpio.bsrr.write(|w| unsafe { w.bits($cs.high()) });
+        $gpio.moder.modify(|r, w| unsafe {
+            w.bits((r.bits() & !(0b11 << (pin * 2))) | (0b01 << (pin * 2)))
+        });
+        $gpio
+            .ospeedr
+            .modify(|r, w| unsafe { w.bits(r.bits() | (0b11 << (pin * 2))) });
+        $gpio
+            .otyper
+            .modify(|r, w| unsafe { w.bits(r.bits() & !(1 << pin)) });
+    }};
+}
+
+/// 端子を代替機能(プッシュプル・高速)にする
+macro_rules! pin_alternate {
+    ($gpio:expr, $pin:expr, $af:expr) => {{
+        let pin = $pin as u32;
+        let af = $af as u32;
+        if pin < 8 {
+            $gpio.afrl.modify(|r, w| unsafe {
+                w.bits((r.bits() & !(0xF << (pin * 4))) | (af << (pin * 4)))
+            });
+        } else {
+            $gpio.afrh.modify(|r, w| unsafe {
+                w.bits((r.bits() & !(0xF << ((pin - 8) * 4))) | (af << ((pin - 8) * 4)))
+            });
+        }
+        $gpio.moder.modify(|r, w| unsafe {
+            w.bits((r.bits() & !(0b11 << (pin * 2))) | (0b10 << (pin * 2)))
+        });
+        $gpio
+            .ospeedr
+            .modify(|r, w| unsafe { w.bits(r.bits() | (0b11 << (pin * 2))) });
+        $gpio
+            .otyper
+            .modify(|r, w| unsafe { w.bits(r.bits() & !(1 << pin)) });
+    }};
+}
+
+/// SPIをマスター・送信専用・16bitに設定し、有効にする
+macro_rules! spi_enable {
+    ($spi:expr) => {{
+        $spi.cr1.modify(|_, w| {
+            w.bidimode().unidirectional().
+            dff().sixteen_bit().
+            lsbfirst().msbfirst().
+            br().div4(). // 基準クロックは48MHz
+            mstr().master().
+            cpol().idle_low().
+            cpha().first_edge().
+            ssm().enabled().
+            ssi().slave_not_selected()
+        });
+        $spi.cr1.modify(|_, w| w.spe().enabled());
+    }};
+}
+
+/// SPI + DMA2による送信路
+pub struct SpiDma<'a> {
+    device: &'a stm32f401::Peripherals,
+    config: SpiDmaConfig,
+    writing: Option<usize>, // 組み立て中のDMAバッファ番号
+    packet_top: bool,       // 次のワードがパケットの先頭か
+}
+
+impl<'a> SpiDma<'a> {
+    /// 標準構成(SPI1 / CS:PA4 / チャンネルA)で初期化する
+    ///
+    /// # Panics
+    /// チャンネルAが、既に他のSpiDmaで使われている場合
+    pub fn new(device: &'a stm32f401::Peripherals) -> SpiDma<'a> {
+        Self::init(device, SpiDmaConfig::default())
+    }
+
+    /// 指定の構成で初期化する
+    ///
+    /// CSの端子がポートにない場合(ポートHの2以上など)は、Error::OutOfRange
+    ///
+    /// # Panics
+    /// 指定のDMAチャンネルが、既に他のSpiDmaで使われている場合
+    pub fn with_config(
+        device: &'a stm32f401::Peripherals,
+        config: SpiDmaConfig,
+    ) -> Result<SpiDma<'a>> {
+        if !config.cs.is_valid() {
+            return Err(Error::OutOfRange);
+        }
+        Ok(Self::init(device, config))
+    }
+
+    /// 構成の確認済みの初期化
+    fn init(device: &'a stm32f401::Peripherals, config: SpiDmaConfig) -> SpiDma<'a> {
+        let claimed = free(|cs| {
+            let mut state = DMA_STATE[config.channel.index()].borrow(cs).borrow_mut();
+            let claimed = state.claimed;
+            state.claimed = true;
+            claimed
+        });
+        assert!(!claimed);
+
+        let spi_dma = SpiDma {
+            device,
+            config,
+            writing: None,
+            packet_top: false,
+        };
+        spi_dma.gpio_setup();
+        spi_dma.spi_setup();
+        spi_dma.tim1_setup();
+        spi_dma.dma_setup();
+        spi_dma
+    }
+
+    /// 送信路の構成
+    pub fn config(&self) -> &SpiDmaConfig {
+        &self.config
+    }
+
+    /// 1フレーム分のDMA送信を開始する
+    fn start_frame(device: &stm32f401::Peripherals, channel: DmaChannel, buff: &'static DmaBuff) {
+        let dma = &device.DMA2;
+        let word_st = &dma.st[channel.word_stream()];
+        let cs_st = &dma.st[channel.cs_stream()];
+        let slots = buff.slots() as u16;
+        word_st.m0ar.write(|w| w.m0a().bits(buff.words_ptr()));
+        word_st.ndtr.write(|w| w.ndt().bits(slots));
+        cs_st.m0ar.write(|w| w.m0a().bits(buff.cs_ptr()));
+        cs_st.ndtr.write(|w| w.ndt().bits(slots));
+        Self::clear_dma_flags(device, channel);
+        cs_st.cr.modify(|_, w| w.en().enabled());
+        word_st.cr.modify(|_, w| w.en().enabled());
+
+        // 最初のイベントが、CS→ワードの順となるようにDMA要求を有効にする
+        let tim1 = &device.TIM1;
+        free(|_| {
+            if tim1.cr1.read().cen().is_disabled() {
+                // 停止中なので、ワード比較の直後からカウント開始
+                tim1.cnt
+                    .write(|w| unsafe { w.cnt().bits(channel.word_compare() + 1) });
+                Self::enable_requests(device, channel, true);
+                tim1.cr1.modify(|_, w| w.cen().enabled());
+            } else {
+                // 他のチャンネルが転送中なので、次のワード比較の割込みで有効にする
+                Self::clear_compare_flag(device, channel);
+                Self::enable_arm_interrupt(device, channel, true);
+            }
+        });
+    }
+
+    /// ワード比較の割込み(TIM1_CC)の処理
+    ///   送信開始を待っているチャンネルのDMA要求を有効にする
+    ///   ワード比較の直後の半スロット(CS比較〜ワード比較の区間を含まない)を過ぎていれば、
+    ///   次のスロットの割込みまで待つ
+    fn on_compare(device: &stm32f401::Peripherals) {
+        let tim1 = &device.TIM1;
+        for channel in [DmaChannel::A, DmaChannel::B].iter().copied() {
+            if !Self::is_armed(device, channel) || !Self::is_compare_flag(device, channel) {
+                continue;
+            }
+            Self::clear_compare_flag(device, channel);
+            let word = channel.word_compare();
+            let cnt = tim1.cnt.read().cnt().bits();
+            let since = (cnt + SLOT_PERIOD - word - 1) % SLOT_PERIOD;
+            if since < SLOT_PERIOD / 2 {
+                Self::enable_arm_interrupt(device, channel, false);
+                Self::enable_requests(device, channel, true);
+            }
+        }
+    }
+
+    /// チャンネルのワード比較の割込みを有効(true)/無効(false)にする
+    fn enable_arm_interrupt(device: &stm32f401::Peripherals, channel: DmaChannel, enable: bool) {
+        let dier = &device.TIM1.dier;
+        match channel {
+            DmaChannel::A => dier.modify(|_, w| w.cc1ie().bit(enable)),
+            DmaChannel::B => dier.modify(|_, w| w.cc2ie().bit(enable)),
+        }
+    }
+
+    /// チャンネルが、ワード比較の割込みで送信開始を待っているか
+    fn is_armed(device: &stm32f401::Peripherals, channel: DmaChannel) -> bool {
+        let dier = device.TIM1.dier.read();
+        match channel {
+            DmaChannel::A => dier.cc1ie().bit_is_set(),
+            DmaChannel::B => dier.cc2ie().bit_is_set(),
+        }
+    }
+
+    /// チャンネルのワード比較のフラグ(CCxIF)が立っているか
+    fn is_compare_flag(device: &stm32f401::Peripherals, channel: DmaChannel) -> bool {
+        let sr = device.TIM1.sr.read();
+        match channel {
+            DmaChannel::A => sr.cc1if().bit_is_set(),
+            DmaChannel::B => sr.cc2if().bit_is_set(),
+        }
+    }
+
+    /// チャンネルのワード比較のフラグ(CCxIF)をクリアする
+    ///   0の書き込みでクリア、1は影響しない
+    fn clear_compare_flag(device: &stm32f401::Peripherals, channel: DmaChannel) {
+        let bit = match channel {
+            DmaChannel::A => 1, // CC1IF
+            DmaChannel::B => 2, // CC2IF
+        };
+        device.TIM1.sr.write(|w| unsafe { w.bits(!(1 << bit)) });
+    }
+
+    /// フレームの送信終了処理
+    ///   DMA要求を無効にし、全チャンネルが終了していればTIM1を止める
+    ///   エラーで中断した場合に備え、DMAストリームも停止する
+    ///   停止(EN=0)を確かめてからフラグをクリアし、遅れて立つフラグを残さない
+    fn stop_frame(device: &stm32f401::Peripherals, channel: DmaChannel) {
+        let tim1 = &device.TIM1;
+        Self::enable_requests(device, channel, false);
+        Self::enable_arm_interrupt(device, channel, false);
+        let dier = tim1.dier.read();
+        if dier.cc1de().is_disabled()
+            && dier.cc2de().is_disabled()
+            && dier.cc3de().is_disabled()
+            && dier.cc4de().is_disabled()
+            && dier.cc1ie().is_disabled()
+            && dier.cc2ie().is_disabled()
+        {
+            tim1.cr1.modify(|_, w| w.cen().disabled());
+        }
+        let dma = &device.DMA2;
+        let word_st = &dma.st[channel.word_stream()];
+        let cs_st = &dma.st[channel.cs_stream()];
+        word_st.cr.modify(|_, w| w.en().disabled());
+        cs_st.cr.modify(|_, w| w.en().disabled());
+        // 実行中の1転送の終了で停止する(DMA要求は無効にしてあるので、すぐに止まる)
+        while word_st.cr.read().en().is_enabled() || cs_st.cr.read().en().is_enabled() {}
+        Self::clear_dma_flags(device, channel);
+    }
+
+    /// チャンネルのTIM1 DMA要求を有効(true)/無効(false)にする
+    fn enable_requests(device: &stm32f401::Peripherals, channel: DmaChannel, enable: bool) {
+        let dier = &device.TIM1.dier;
+        match channel {
+            DmaChannel::A => dier.modify(|_, w| w.cc1de().bit(enable).cc4de().bit(enable)),
+            DmaChannel::B => dier.modify(|_, w| w.cc2de().bit(enable).cc3de().bit(enable)),
+        }
+    }
+
+    /// チャンネルのDMAストリームのエラーフラグを調べる
+    fn dma_error(device: &stm32f401::Peripherals, channel: DmaChannel) -> Option<Error> {
+        let lisr = device.DMA2.lisr.read();
+        let hisr = device.DMA2.hisr.read();
+        let (transfer, fifo) = match channel {
+            DmaChannel::A => (
+                lisr.teif1().is_error() || hisr.teif4().is_error(),
+                lisr.dmeif1().is_error() || hisr.dmeif4().is_error(),
+            ),
+            DmaChannel::B => (
+                lisr.teif2().is_error() || hisr.teif6().is_error(),
+                lisr.dmeif2().is_error() || hisr.dmeif6().is_error(),
+            ),
+        };
+        if transfer {
+            Some(Error::TransferError)
+        } else if fifo {
+            Some(Error::FifoError)
+        } else {
+            None
+        }
+    }
+
+    /// チャンネルのワード送信のストリームが、転送を完了したか
+    fn is_transfer_complete(device: &stm32f401::Peripherals, channel: DmaChannel) -> bool {
+        let lisr = device.DMA2.lisr.read();
+        match channel {
+            DmaChannel::A => lisr.tcif1().is_complete(),
+            DmaChannel::B => lisr.tcif2().is_complete(),
+        }
+    }
+
+    /// フレームの送信終了(またはエラーによる中断)時の割込み処理
+    ///   転送中のフレームが無い、または完了・エラーのフラグが無い割込みは、
+    ///   フラグをクリアするのみとする。(転送中のフレームのみを終了させる)
+    fn on_frame_end(device: &stm32f401::Peripherals, channel: DmaChannel) {
+        let error = Self::dma_error(device, channel);
+        let complete = Self::is_transfer_complete(device, channel);
+        let in_flight = free(|cs| {
+            let state = DMA_STATE[channel.index()].borrow(cs).borrow();
+            state.active.is_some()
+        });
+        if !in_flight || (error.is_none() && !complete) {
+            Self::clear_dma_flags(device, channel);
+            return;
+        }
+        // 最後のスロットの空ワードは送信中だが、
+        // データの確定は済んでいるので待つ必要はない
+        Self::stop_frame(device, channel);
+
+        //保留中のフレームがあれば送信開始
+        let next = free(|cs| {
+            let mut state = DMA_STATE[channel.index()].borrow(cs).borrow_mut();
+            if error.is_some() {
+                state.error = error;
+            }
+            state.next_frame()
+        });
+        if let Some(index) = next {
+            Self::start_frame(device, channel, &DMA_BUFF[channel.index()][index]);
+        }
+    }
+
+    /// チャンネルのDMAストリームの完了・エラーフラグをクリアする
+    fn clear_dma_flags(device: &stm32f401::Peripherals, channel: DmaChannel) {
+        let dma = &device.DMA2;
+        match channel {
+            DmaChannel::A => {
+                dma.lifcr.write(|w| {
+                    w.ctcif1().clear();
+                    w.chtif1().clear();
+                    w.cteif1().clear();
+                    w.cdmeif1().clear();
+                    w.cfeif1().clear()
+                });
+                dma.hifcr.write(|w| {
+                    w.ctcif4().clear();
+                    w.chtif4().clear();
+                    w.cteif4().clear();
+                    w.cdmeif4().clear();
+                    w.cfeif4().clear()
+                });
+            }
+            DmaChannel::B => {
+                dma.lifcr.write(|w| {
+                    w.ctcif2().clear();
+                    w.chtif2().clear();
+                    w.cteif2().clear();
+                    w.cdmeif2().clear();
+                    w.cfeif2().clear()
+                });
+                dma.hifcr.write(|w| {
+                    w.ctcif6().clear();
+                    w.chtif6().clear();
+                    w.cteif6().clear();
+                    w.cdmeif6().clear();
+                    w.cfeif6().clear()
+                });
+            }
+        }
+    }
+
+    /// チャンネルのDMAストリームが停止しているか
+    fn is_dma_inactive(device: &stm32f401::Peripherals, channel: DmaChannel) -> Result<()> {
+        let dma = &device.DMA2;
+        if dma.st[channel.word_stream()].cr.read().en().is_enabled()
+            || dma.st[channel.cs_stream()].cr.read().en().is_enabled()
+        {
+            Err(Error::DmaStreamActive)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// SPIのセットアップ
+    ///   SPIは常に有効とし、DRへの書き込みはTIM1起動のDMAで行う
+    fn spi_setup(&self) {
+        let rcc = &self.device.RCC;
+        // 電源投入
+        match self.config.spi {
+            SpiPort::Spi1 => {
+                rcc.apb2enr.modify(|_, w| w.spi1en().enabled());
+                spi_enable!(self.device.SPI1);
+            }
+            SpiPort::Spi2 => {
+                rcc.apb1enr.modify(|_, w| w.spi2en().enabled());
+                spi_enable!(self.device.SPI2);
+            }
+            SpiPort::Spi3 => {
+                rcc.apb1enr.modify(|_, w| w.spi3en().enabled());
+                spi_enable!(self.device.SPI3);
+            }
+        }
+    }
+
+    /// SPIのデータレジスタのアドレス
+    fn spi_dr(&self) -> u32 {
+        match self.config.spi {
+            SpiPort::Spi1 => &self.device.SPI1.dr as *const _ as u32,
+            SpiPort::Spi2 => &self.device.SPI2.dr as *const _ as u32,
+            SpiPort::Spi3 => &self.device.SPI3.dr as *const _ as u32,
+        }
+    }
+
+    /// TIM1のセットアップ
+    ///   出力端子は使わず、比較チャンネルのDMA要求のみ使用する
+    ///   他のチャンネルで設定済みであれば、比較値のみ設定する
+    fn tim1_setup(&self) {
+        let channel = self.config.channel;
+        let tim1 = &self.device.TIM1;
+        if self.device.RCC.apb2enr.read().tim1en().is_disabled() {
+            // 電源投入
+            self.device.RCC.apb2enr.modify(|_, w| w.tim1en().enabled());
+            tim1.cr1.modify(|_, w| w.cen().disabled());
+            tim1.psc.write(|w| w.psc().bits(0));
+            tim1.arr.write(|w| unsafe { w.arr().bits(SLOT_PERIOD - 1) });
+        }
+        match channel {
+            DmaChannel::A => {
+                tim1.ccr1
+                    .write(|w| unsafe { w.ccr().bits(channel.word_compare()) });
+                tim1.ccr4
+                    .write(|w| unsafe { w.ccr().bits(channel.cs_compare()) });
+            }
+            DmaChannel::B => {
+                tim1.ccr2
+                    .write(|w| unsafe { w.ccr().bits(channel.word_compare()) });
+                tim1.ccr3
+                    .write(|w| unsafe { w.ccr().bits(channel.cs_compare()) });
+            }
+        }
+    }
+
+    /// gpioのセットアップ
+    fn gpio_setup(&self) {
+        let rcc = &self.device.RCC;
+        // SPI端子割付け(MOSI, CLK)
+        match self.config.spi {
+            SpiPort::Spi1 => {
+                rcc.ahb1enr.modify(|_, w| w.gpioaen().enabled());
+                pin_alternate!(self.device.GPIOA, 7, 5); // SPI1_MOSI
+                pin_alternate!(self.device.GPIOA, 5, 5); // SPI1_CLK
+            }
+            SpiPort::Spi2 => {
+                rcc.ahb1enr.modify(|_, w| w.gpioben().enabled());
+                pin_alternate!(self.device.GPIOB, 15, 5); // SPI2_MOSI
+                pin_alternate!(self.device.GPIOB, 13, 5); // SPI2_CLK
+            }
+            SpiPort::Spi3 => {
+                rcc.ahb1enr.modify(|_, w| w.gpiocen().enabled());
+                pin_alternate!(self.device.GPIOC, 12, 6); // SPI3_MOSI
+                pin_alternate!(self.device.GPIOC, 10, 6); // SPI3_CLK
+            }
+        }
+
+        // CS端子
+        let cs = self.config.cs;
+        match cs.port {
+            GpioPort::A => {
+                rcc.ahb1enr.modify(|_, w| w.gpioaen().enabled());
+                cs_output!(self.device.GPIOA, cs);
+            }
+            GpioPort::B => {
+                rcc.ahb1enr.modify(|_, w| w.gpioben().enabled());
+                cs_output!(self.device.GPIOB, cs);
+            }
+            GpioPort::C => {
+                rcc.ahb1enr.modify(|_, w| w.gpiocen().enabled());
+                cs_output!(self.device.GPIOC, cs);
+            }
+            GpioPort::D => {
+                rcc.ahb1enr.modify(|_, w| w.gpioden().enabled());
+                cs_output!(self.device.GPIOD, cs);
+            }
+            GpioPort::E => {
+                rcc.ahb1enr.modify(|_, w| w.gpioeen().enabled());
+                cs_output!(self.device.GPIOE, cs);
+            }
+            GpioPort::H => {
+                rcc.ahb1enr.modify(|_, w| w.gpiohen().enabled());
+                cs_output!(self.device.GPIOH, cs);
+            }
+        }
+    }
+
+    /// CS端子のポートのBSRRのアドレス
+    fn cs_bsrr(&self) -> u32 {
+        match self.config.cs.port {
+            GpioPort::A => &self.device.GPIOA.bsrr as *const _ as u32,
+            GpioPort::B => &self.device.GPIOB.bsrr as *const _ as u32,
+            GpioPort::C => &self.device.GPIOC.bsrr as *const _ as u32,
+            GpioPort::D => &self.device.GPIOD.bsrr as *const _ as u32,
+            GpioPort::E => &self.device.GPIOE.bsrr as *const _ as u32,
+            GpioPort::H => &self.device.GPIOH.bsrr as *const _ as u32,
+        }
+    }
+
+    /// DMAのセットアップ
+    fn dma_setup(&self) {
+        self.device.RCC.ahb1enr.modify(|_, w| w.dma2en().enabled());
+        let dma = &self.device.DMA2;
+        let channel = self.config.channel;
+
+        // ワード送信 チャンネル6(TIM1の比較)使用
+        let word_st = &dma.st[channel.word_stream()];
+        word_st.cr.modify(|_, w| {
+            w.chsel().bits(6u8);
+            w.mburst().single();
+            w.pburst().single();
+            w.ct().memory0();
+            w.dbm().disabled();
+            w.pl().high();
+            w.pincos().psize();
+            w.msize().bits16();
+            w.psize().bits16();
+            w.minc().incremented();
+            w.pinc().fixed();
+            w.circ().disabled();
+            w.dir().memory_to_peripheral();
+            w.tcie().enabled();
+            w.htie().disabled();
+            w.teie().enabled();
+            w.dmeie().enabled()
+        });
+        word_st.fcr.modify(|_, w| {
+            w.feie().disabled();
+            w.dmdis().disabled();
+            w.fth().half()
+        });
+        word_st.par.write(|w| w.pa().bits(self.spi_dr()));
+
+        // CS制御 チャンネル6(TIM1の比較)使用
+        // ワードより先に処理されるよう、優先度を上げる
+        let cs_st = &dma.st[channel.cs_stream()];
+        cs_st.cr.modify(|_, w| {
+            w.chsel().bits(6u8);
+            w.mburst().single();
+            w.pburst().single();
+            w.ct().memory0();
+            w.dbm().disabled();
+            w.pl().very_high();
+            w.pincos().psize();
+            w.msize().bits32();
+            w.psize().bits32();
+            w.minc().incremented();
+            w.pinc().fixed();
+            w.circ().disabled();
+            w.dir().memory_to_peripheral();
+            w.tcie().disabled();
+            w.htie().disabled();
+            w.teie().enabled();
+            w.dmeie().enabled()
+        });
+        cs_st.fcr.modify(|_, w| {
+            w.feie().disabled();
+            w.dmdis().disabled();
+            w.fth().half()
+        });
+        cs_st.par.write(|w| w.pa().bits(self.cs_bsrr()));
+
+        unsafe {
+            cortex_m::peripheral::NVIC::unmask(stm32f401::interrupt::TIM1_CC);
+            match channel {
+                DmaChannel::A => {
+                    cortex_m::peripheral::NVIC::unmask(stm32f401::interrupt::DMA2_STREAM1);
+                    cortex_m::peripheral::NVIC::unmask(stm32f401::interrupt::DMA2_STREAM4);
+                }
+                DmaChannel::B => {
+                    cortex_m::peripheral::NVIC::unmask(stm32f401::interrupt::DMA2_STREAM2);
+                    cortex_m::peripheral::NVIC::unmask(stm32f401::interrupt::DMA2_STREAM6);
+                }
+            }
+        }
+    }
+
+    /// このチャンネルのDMAバッファ
+    fn buffs(&self) -> &'static [DmaBuff; 2] {
+        &DMA_BUFF[self.config.channel.index()]
+    }
+
+    /// このチャンネルのDMA状態
+    fn state(&self) -> &'static Mutex<RefCell<DmaState>> {
+        &DMA_STATE[self.config.channel.index()]
+    }
+
+    /// 組み立て中のDMAバッファ
+    fn writing_buff(&self) -> Result<&'static DmaBuff> {
+        let index = self.writing.ok_or(Error::NotInitialised)?;
+        Ok(&self.buffs()[index])
+    }
+}
+
+impl Transport for SpiDma<'_> {
+    /// 転送中でないDMAバッファを確保する
+    /// 保留中のフレームがあれば、破棄してそのバッファを使う
+    /// 前回までの転送でエラーがあれば、そのエラーを返す
+    fn begin(&mut self) -> Result<()> {
+        let index = free(|cs| {
+            let mut state = self.state().borrow(cs).borrow_mut();
+            if let Some(error) = state.error.take() {
+                return Err(error);
+            }
+            state.pending = None;
+            Ok(match state.active {
+                Some(0) => 1,
+                _ => 0,
+            })
+        })?;
+        self.buffs()[index].clear_buff();
+        self.writing = Some(index);
+        self.packet_top = false;
+        Ok(())
+    }
+
+    fn select(&mut self) -> Result<()> {
+        self.packet_top = true;
+        Ok(())
+    }
+
+    fn write(&mut self, words: &[u16]) -> Result<()> {
+        let buff = self.writing_buff()?;
+        for word in words {
+            let cs = if self.packet_top {
+                self.config.cs.low()
+            } else {
+                CS_KEEP
+            };
+            buff.add_slot(*word, cs)?;
+            self.packet_top = false;
+        }
+        Ok(())
+    }
+
+    /// 空ワードと共にCSを立ち上げ、データを確定させる
+    fn deselect(&mut self) -> Result<()> {
+        self.packet_top = false;
+        self.writing_buff()?.add_slot(0x0000, self.config.cs.high())
+    }
+
+    /// 転送中でなければ送信を開始し、転送中であれば保留する
+    fn flush(&mut self) -> Result<()> {
+        let index = self.writing.take().ok_or(Error::NotInitialised)?;
+        if self.buffs()[index].slots() == 0 {
+            return Ok(()); // 送るものが無い
+        }
+        let channel = self.config.channel;
+        let start = free(|cs| {
+            let mut state = self.state().borrow(cs).borrow_mut();
+            if state.active.is_none() {
+                state.active = Some(index);
+                true
+            } else {
+                state.pending = Some(index);
+                false
+            }
+        });
+        if start {
+            if let Err(e) = Self::is_dma_inactive(self.device, channel) {
+                free(|cs| self.state().borrow(cs).borrow_mut().active = None);
+                return Err(e);
+            }
+            Self::start_frame(self.device, channel, &self.buffs()[index]);
+        }
+        Ok(())
+    }
+
+    fn is_busy(&self) -> bool {
+        free(|cs| self.state().borrow(cs).borrow().active.is_some())
+    }
+
+    fn is_pending(&self) -> bool {
+        free(|cs| self.state().borrow(cs).borrow().pending.is_some())
+    }
+}
+
+/// TIM1 比較 割込み関数
+///   TIM1の動作中に送信を始めるチャンネルの、ワード比較時に1回だけ呼ばれる
+#[interrupt]
+fn TIM1_CC() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    SpiDma::on_compare(&device);
+}
+
+/// DMA2 Stream1(チャンネルA ワード送信) 割込み関数
+///   1フレームの送信完了時、及びエラー発生時に呼ばれる
+#[interrupt]
+fn DMA2_STREAM1() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    SpiDma::on_frame_end(&device, DmaChannel::A);
+}
+
+/// DMA2 Stream4(チャンネルA CS制御) 割込み関数
+///   エラー発生時のみ呼ばれる
+#[interrupt]
+fn DMA2_STREAM4() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    SpiDma::on_frame_end(&device, DmaChannel::A);
+}
+
+/// DMA2 Stream2(チャンネルB ワード送信) 割込み関数
+///   1フレームの送信完了時、及びエラー発生時に呼ばれる
+#[interrupt]
+fn DMA2_STREAM2() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    SpiDma::on_frame_end(&device, DmaChannel::B);
+}
+
+/// DMA2 Stream6(チャンネルB CS制御) 割込み関数
+///   エラー発生時のみ呼ばれる
+#[interrupt]
+fn DMA2_STREAM6() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    SpiDma::on_frame_end(&device, DmaChannel::B);
+}
+
+impl Drop for SpiDma<'_> {
+    /// 送信を止め、DMAチャンネルを解放する
+    fn drop(&mut self) {
+        let channel = self.config.channel;
+        free(|cs| {
+            Self::stop_frame(self.device, channel);
+            // フレームの途中で止めた場合に備え、CSを通信無効(HI)に戻す
+            unsafe {
+                core::ptr::write_volatile(self.cs_bsrr() as *mut u32, self.config.cs.high());
+            }
+            let mut state = DMA_STATE[channel.index()].borrow(cs).borrow_mut();
+            state.active = None;
+            state.pending = None;
+            state.error = None;
+            state.claimed = false;
+        });
+    }
+}
+
+/// DMAバッファの使用状況
+struct DmaState {
+    active: Option<usize>,  // 転送中のバッファ番号
+    pending: Option<usize>, // 転送待ちのバッファ番号
+    error: Option<Error>,   // 未通知のDMAエラー
+    claimed: bool,          // SpiDmaで使用中か
+}
+
+impl DmaState {
+    /// 転送の終わったフレームを、保留中のフレームに切り替える
+    fn next_frame(&mut self) -> Option<usize> {
+        self.active = self.pending.take();
+        self.active
+    }
+}
+
+/// DMAチャンネル毎の状態
+static DMA_STATE: [Mutex<RefCell<DmaState>>; 2] = [
+    Mutex::new(RefCell::new(DmaState {
+        active: None,
+        pending: None,
+        error: None,
+        claimed: false,
+    })),
+    Mutex::new(RefCell::new(DmaState {
+        active: None,
+        pending: None,
+        error: None,
+        claimed: false,
+    })),
+];
+
+/// DMAバッファに積めるパケット数(表示8行 + レジスタ設定5種)
+const DMA_PACKETS: usize = 13;
+
+/// DMAバッファのスロット数(パケット毎に、確定用の1スロットが加わる)
+const DMA_SLOTS: usize = (MAX_MODULES + 1) * DMA_PACKETS;
+
+/// DMAバッファ領域(チャンネル毎に2面)
+/// 　グローバル変数・spi_dmaモジュール以外での操作禁止
+///   DMA_STATEにて転送中・転送待ちとなっていないバッファのみ操作可能
+static DMA_BUFF: [[DmaBuff; 2]; 2] = [
+    [DMA_BUFF_INIT, DMA_BUFF_INIT],
+    [DMA_BUFF_INIT, DMA_BUFF_INIT],
+];
+
+use core::cell::UnsafeCell;
+struct DmaBuff {
+    words: UnsafeCell<[u16; DMA_SLOTS]>, // SPIx_DRへ送るワード
+    cs: UnsafeCell<[u32; DMA_SLOTS]>,    // GPIOx_BSRRへ送るCS操作
+    slot_count: UnsafeCell<usize>,
+}
+
+const DMA_BUFF_INIT: DmaBuff = DmaBuff {
+    words: UnsafeCell::new([0u16; DMA_SLOTS]),
+    cs: UnsafeCell::new([CS_KEEP; DMA_SLOTS]),
+    slot_count: UnsafeCell::new(0),
+};
+
+unsafe impl Sync for DmaBuff {}
+
+impl DmaBuff {
+    pub fn clear_buff(&self) {
+        unsafe {
+            *self.slot_count.get() = 0;
+        }
+    }
+
+    pub fn add_slot(&self, word: u16, cs: u32) -> Result<()> {
+        unsafe {
+            let index = *self.slot_count.get();
+            if index >= DMA_SLOTS {
+                return Err(Error::BufferOverflow);
+            }
+            (*self.words.get())[index] = word;
+            (*self.cs.get())[index] = cs;
+            *self.slot_count.get() += 1;
+        }
+        Ok(())
+    }
+
+    fn slots(&self) -> usize {
+        unsafe { *self.slot_count.get() }
+    }
+
+    fn words_ptr(&self) -> u32 {
+        self.words.get() as u32
+    }
+
+    fn cs_ptr(&self) -> u32 {
+        self.cs.get() as u32
+    }
+}
@@ -0,0 +1,415 @@
+//! Matrix LED(MAX7219チェーン)・I2C LEDドライバ・WS2812チェーンへのデータ送信路
+//!
+//! MAX7219へは、CS(LOAD)をLOにした状態で16bitワードをチェーン長分送り、
+//! CSをHIに戻した時点で各チップにデータが確定する。
+//! Matrixは、このワード列とCS制御のみをTransport経由で行う。
+
+use crate::matrix_led::{Error, Result};
+use crate::rgb_matrix::{Rgb, MAX_PIXELS};
+
+/// MAX7219チェーンへのワード列送信路
+///
+/// 1フレームは、begin()で開始し、select()〜write()〜deselect()で
+/// 区切られたパケットを積み、flush()で送信する。
+/// 実際の送信タイミングは実装依存(即時でも、溜めてからDMAでも良い)
+///
+/// 転送中にflush()したフレームは、転送待ち(pending)となる。
+/// 転送待ちのフレームは、次のbegin()で破棄される。
+pub trait Transport {
+    /// フレームの組み立てを開始する
+    /// 転送待ちのフレームがあれば、破棄する
+    fn begin(&mut self) -> Result<()>;
+    /// CS(LOAD)を通信有効(LO)にする
+    fn select(&mut self) -> Result<()>;
+    /// 16bitワード列を送る
+    fn write(&mut self, words: &[u16]) -> Result<()>;
+    /// CS(LOAD)を通信無効(HI)にし、データを確定させる
+    fn deselect(&mut self) -> Result<()>;
+    /// 組み立てたフレームを送信する
+    /// 転送中であれば、転送待ちとして即座に戻る
+    fn flush(&mut self) -> Result<()>;
+    /// 転送中か
+    fn is_busy(&self) -> bool;
+    /// 転送待ちのフレームがあるか
+    fn is_pending(&self) -> bool;
+}
+
+/// 記録可能なワード数
+pub const RECORD_WORDS: usize = 1024;
+/// 記録可能なパケット数
+pub const RECORD_PACKETS: usize = 256;
+
+/// 送信されたワード列をメモリに記録するTransport
+///
+/// ホスト上でのテスト用。実機へは何も送らない。
+pub struct RecordingTransport {
+    words: [u16; RECORD_WORDS],
+    word_count: usize,
+    packet_ends: [usize; RECORD_PACKETS], // 各パケットの終端(wordsのindex)
+    packet_count: usize,
+    flush_count: usize,
+}
+
+impl RecordingTransport {
+    pub const fn new() -> Self {
+        RecordingTransport {
+            words: [0; RECORD_WORDS],
+            word_count: 0,
+            packet_ends: [0; RECORD_PACKETS],
+            packet_count: 0,
+            flush_count: 0,
+        }
+    }
+
+    /// 記録を消去する
+    pub fn clear(&mut self) {
+        self.word_count = 0;
+        self.packet_count = 0;
+        self.flush_count = 0;
+    }
+
+    /// 送信された全ワード(送信順)
+    pub fn words(&self) -> &[u16] {
+        &self.words[0..self.word_count]
+    }
+
+    /// CSで確定されたパケット毎のワード列
+    pub fn packets(&self) -> RecordedPackets<'_> {
+        RecordedPackets {
+            rec: self,
+            index: 0,
+        }
+    }
+
+    /// flush()の呼び出し回数
+    pub fn flush_count(&self) -> usize {
+        self.flush_count
+    }
+}
+
+impl Default for RecordingTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn begin(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn select(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write(&mut self, words: &[u16]) -> Result<()> {
+        if self.word_count + words.len() > RECORD_WORDS {
+            return Err(Error::BufferOverflow);
+        }
+        self.words[self.word_count..self.word_count + words.len()].copy_from_slice(words);
+        self.word_count += words.len();
+        Ok(())
+    }
+
+    fn deselect(&mut self) -> Result<()> {
+        if self.packet_count >= RECORD_PACKETS {
+            return Err(Error::BufferOverflow);
+        }
+        self.packet_ends[self.packet_count] = self.word_count;
+        self.packet_count += 1;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.flush_count += 1;
+        Ok(())
+    }
+
+    /// 記録は即座に終わるので、常に転送完了
+    fn is_busy(&self) -> bool {
+        false
+    }
+
+    fn is_pending(&self) -> bool {
+        false
+    }
+}
+
+/// RecordingTransportのパケット用Iterator
+pub struct RecordedPackets<'a> {
+    rec: &'a RecordingTransport,
+    index: usize,
+}
+
+impl<'a> Iterator for RecordedPackets<'a> {
+    type Item = &'a [u16];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.rec.packet_count {
+            return None;
+        }
+        let start = if self.index == 0 {
+            0
+        } else {
+            self.rec.packet_ends[self.index - 1]
+        };
+        let end = self.rec.packet_ends[self.index];
+        self.index += 1;
+        Some(&self.rec.words[start..end])
+    }
+}
+
+/// I2Cデバイスへのバイト列送信路
+///
+/// 1フレームは、begin()で開始し、write()で1トランザクション
+/// (START〜アドレス〜データ〜STOP)ずつ積み、flush()で送信する。
+/// 転送待ちのフレームの扱いは、Transportと同じ。
+pub trait I2cTransport {
+    /// フレームの組み立てを開始する
+    /// 転送待ちのフレームがあれば、破棄する
+    fn begin(&mut self) -> Result<()>;
+    /// 7bitアドレスのデバイスへ、バイト列を1トランザクションとして送る
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<()>;
+    /// 組み立てたフレームを送信する
+    /// 転送中であれば、転送待ちとして即座に戻る
+    fn flush(&mut self) -> Result<()>;
+    /// 転送中か
+    fn is_busy(&self) -> bool;
+    /// 転送待ちのフレームがあるか
+    fn is_pending(&self) -> bool;
+}
+
+/// 記録可能なトランザクション数
+pub const RECORD_TRANSACTIONS: usize = 64;
+
+/// 送信されたI2Cトランザクションをメモリに記録するI2cTransport
+///
+/// ホスト上でのテスト用。実機へは何も送らない。
+pub struct RecordingI2c {
+    bytes: [u8; RECORD_WORDS],
+    byte_count: usize,
+    transactions: [(u8, usize); RECORD_TRANSACTIONS], // (アドレス, 終端のindex)
+    transaction_count: usize,
+    flush_count: usize,
+}
+
+impl RecordingI2c {
+    pub const fn new() -> Self {
+        RecordingI2c {
+            bytes: [0; RECORD_WORDS],
+            byte_count: 0,
+            transactions: [(0, 0); RECORD_TRANSACTIONS],
+            transaction_count: 0,
+            flush_count: 0,
+        }
+    }
+
+    /// 記録を消去する
+    pub fn clear(&mut self) {
+        self.byte_count = 0;
+        self.transaction_count = 0;
+        self.flush_count = 0;
+    }
+
+    /// トランザクション毎の(アドレス, バイト列)
+    pub fn transactions(&self) -> RecordedTransactions<'_> {
+        RecordedTransactions {
+            rec: self,
+            index: 0,
+        }
+    }
+
+    /// flush()の呼び出し回数
+    pub fn flush_count(&self) -> usize {
+        self.flush_count
+    }
+}
+
+impl Default for RecordingI2c {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl I2cTransport for RecordingI2c {
+    fn begin(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write(&mut self, address: u8, bytes: &[u8]) -> Result<()> {
+        if self.byte_count + bytes.len() > RECORD_WORDS
+            || self.transaction_count >= RECORD_TRANSACTIONS
+        {
+            return Err(Error::BufferOverflow);
+        }
+        self.bytes[self.byte_count..self.byte_count + bytes.len()].copy_from_slice(bytes);
+        self.byte_count += bytes.len();
+        self.transactions[self.transaction_count] = (address, self.byte_count);
+        self.transaction_count += 1;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.flush_count += 1;
+        Ok(())
+    }
+
+    /// 記録は即座に終わるので、常に転送完了
+    fn is_busy(&self) -> bool {
+        false
+    }
+
+    fn is_pending(&self) -> bool {
+        false
+    }
+}
+
+/// RecordingI2cのトランザクション用Iterator
+pub struct RecordedTransactions<'a> {
+    rec: &'a RecordingI2c,
+    index: usize,
+}
+
+impl<'a> Iterator for RecordedTransactions<'a> {
+    type Item = (u8, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.rec.transaction_count {
+            return None;
+        }
+        let start = if self.index == 0 {
+            0
+        } else {
+            self.rec.transactions[self.index - 1].1
+        };
+        let (address, end) = self.rec.transactions[self.index];
+        self.index += 1;
+        Some((address, &self.rec.bytes[start..end]))
+    }
+}
+
+/// WS2812チェーンへの画素列送信路
+///
+/// 1フレームは、begin()で開始し、write()で画素をチェーンの先頭から順に積み、
+/// flush()で送信する。(ラッチ用のリセット期間は、送信路が付加する)
+/// 転送待ちのフレームの扱いは、Transportと同じ。
+pub trait PixelTransport {
+    /// フレームの組み立てを開始する
+    /// 転送待ちのフレームがあれば、破棄する
+    fn begin(&mut self) -> Result<()>;
+    /// 画素列を送る
+    fn write(&mut self, pixels: &[Rgb]) -> Result<()>;
+    /// 組み立てたフレームを送信する
+    /// 転送中であれば、転送待ちとして即座に戻る
+    fn flush(&mut self) -> Result<()>;
+    /// 転送中か
+    fn is_busy(&self) -> bool;
+    /// 転送待ちのフレームがあるか
+    fn is_pending(&self) -> bool;
+}
+
+/// 送信された画素列をメモリに記録するPixelTransport
+///
+/// ホスト上でのテスト用。実機へは何も送らない。
+/// 記録するのは、最後のフレームのみ。
+pub struct RecordingPixels {
+    pixels: [Rgb; MAX_PIXELS],
+    pixel_count: usize,
+    flush_count: usize,
+}
+
+impl RecordingPixels {
+    pub const fn new() -> Self {
+        RecordingPixels {
+            pixels: [Rgb::new(0, 0, 0); MAX_PIXELS],
+            pixel_count: 0,
+            flush_count: 0,
+        }
+    }
+
+    /// 記録を消去する
+    pub fn clear(&mut self) {
+        self.pixel_count = 0;
+        self.flush_count = 0;
+    }
+
+    /// 最後のフレームの画素列(チェーン順)
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels[0..self.pixel_count]
+    }
+
+    /// flush()の呼び出し回数
+    pub fn flush_count(&self) -> usize {
+        self.flush_count
+    }
+}
+
+impl Default for RecordingPixels {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PixelTransport for RecordingPixels {
+    fn begin(&mut self) -> Result<()> {
+        self.pixel_count = 0;
+        Ok(())
+    }
+
+    fn write(&mut self, pixels: &[Rgb]) -> Result<()> {
+        if self.pixel_count + pixels.len() > MAX_PIXELS {
+            return Err(Error::BufferOverflow);
+        }
+        self.pixels[self.pixel_count..self.pixel_count + pixels.len()].copy_from_slice(pixels);
+        self.pixel_count += pixels.len();
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.flush_count += 1;
+        Ok(())
+    }
+
+    /// 記録は即座に終わるので、常に転送完了
+    fn is_busy(&self) -> bool {
+        false
+    }
+
+    fn is_pending(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_transport_splits_packets() {
+        let mut rec = RecordingTransport::new();
+        rec.begin().unwrap();
+        rec.select().unwrap();
+        rec.write(&[0x0101, 0x0102]).unwrap();
+        rec.deselect().unwrap();
+        rec.select().unwrap();
+        rec.write(&[0x0203]).unwrap();
+        rec.deselect().unwrap();
+        rec.flush().unwrap();
+        assert_eq!(rec.words(), &[0x0101, 0x0102, 0x0203]);
+        let packets: Vec<&[u16]> = rec.packets().collect();
+        assert_eq!(packets, [&[0x0101, 0x0102][..], &[0x0203][..]]);
+        assert_eq!(rec.flush_count(), 1);
+        rec.clear();
+        assert_eq!(rec.packets().count(), 0);
+    }
+
+    #[test]
+    fn recording_transport_overflow() {
+        let mut rec = RecordingTransport::new();
+        assert_eq!(
+            rec.write(&[0; RECORD_WORDS + 1]),
+            Err(Error::BufferOverflow)
+        );
+        assert!(rec.words().is_empty());
+    }
+}
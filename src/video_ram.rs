@@ -0,0 +1,608 @@
+//! Matrix LED用 Video RAM
+//!  1ドット1bit。左上を基点(0,0)とし、各行は左端をMSBとしたバイト列
+//!  1バイトは、1モジュールの1桁(行)に相当する。
+//!  前回の送信以降に変化したバイトを、dirtyとして記録する。
+
+use crate::matrix_led::MAX_MODULES;
+
+/// Video RAMの最大バイト数(8*8モジュール MAX_MODULES個分)
+pub const VRAM_SIZE: usize = MAX_MODULES * 8;
+
+/// 転送元のビットマップと転送先のドットの合成方法
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RasterOp {
+    /// 上書き(dst = src)
+    Copy,
+    /// 論理和(dst = dst | src)
+    Or,
+    /// 論理積(dst = dst & src)
+    And,
+    /// 排他的論理和(dst = dst ^ src)
+    Xor,
+    /// 消去(dst = dst & !src)
+    AndNot,
+}
+
+impl RasterOp {
+    /// 1ドット分の合成結果
+    pub fn apply(self, dst: bool, src: bool) -> bool {
+        match self {
+            RasterOp::Copy => src,
+            RasterOp::Or => dst | src,
+            RasterOp::And => dst & src,
+            RasterOp::Xor => dst ^ src,
+            RasterOp::AndNot => dst & !src,
+        }
+    }
+}
+
+/// Video RAM
+pub struct VideoRam {
+    buff: [u8; VRAM_SIZE],
+    dirty: [bool; VRAM_SIZE], // 前回の送信以降に変化したバイト
+    width: usize,             // 横ドット数(8の倍数)
+    height: usize,            // 縦ドット数
+}
+
+impl VideoRam {
+    /// 指定サイズのVideo RAMを作る
+    ///
+    /// # Panics
+    /// 幅が8の倍数でない、もしくはVRAM_SIZEに収まらない場合
+    pub fn new(width: usize, height: usize) -> VideoRam {
+        assert!(width.is_multiple_of(8) && width / 8 * height <= VRAM_SIZE);
+        VideoRam {
+            buff: [0; VRAM_SIZE],
+            dirty: [true; VRAM_SIZE], // 表示側の内容は不定なので、全て送る
+            width,
+            height,
+        }
+    }
+
+    /// 横ドット数
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// 縦ドット数
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// 一行のバイト数
+    pub fn stride(&self) -> usize {
+        self.width / 8
+    }
+
+    /// 全ドットを消去する
+    pub fn clear(&mut self) {
+        for (byte, dirty) in self.buff.iter_mut().zip(self.dirty.iter_mut()) {
+            if *byte != 0 {
+                *byte = 0;
+                *dirty = true;
+            }
+        }
+    }
+
+    /// 指定行の先頭からcol番目のバイト(8ドット分)を返す
+    pub fn byte(&self, col: usize, y: usize) -> u8 {
+        self.buff[y * self.stride() + col]
+    }
+
+    /// 指定行のcol番目のバイトが、前回の送信以降に変化したか
+    pub fn is_dirty(&self, col: usize, y: usize) -> bool {
+        self.dirty[y * self.stride() + col]
+    }
+
+    /// 全てのバイトを、変化したものとする
+    pub fn invalidate(&mut self) {
+        for dirty in &mut self.dirty[..] {
+            *dirty = true;
+        }
+    }
+
+    /// 変化の記録を消去する(送信完了時)
+    pub fn clear_dirty(&mut self) {
+        for dirty in &mut self.dirty[..] {
+            *dirty = false;
+        }
+    }
+
+    /// 指定ドットの状態を返す。範囲外はfalse
+    pub fn get(&self, x: i32, y: i32) -> bool {
+        match self.index(x, y) {
+            Some((i, mask)) => self.buff[i] & mask != 0,
+            None => false,
+        }
+    }
+
+    /// 指定ドットを点灯(true)/消灯(false)する。範囲外は無視
+    pub fn put(&mut self, x: i32, y: i32, on: bool) {
+        if let Some((i, mask)) = self.index(x, y) {
+            let byte = if on {
+                self.buff[i] | mask
+            } else {
+                self.buff[i] & !mask
+            };
+            if byte != self.buff[i] {
+                self.buff[i] = byte;
+                self.dirty[i] = true;
+            }
+        }
+    }
+
+    /// 指定の場所に、指定の矩形のビットマップをORで描く。
+    ///
+    /// ビットマップの最大幅は8。幅が8未満の場合は、LSBより詰めること。
+    /// 矩形の高さは、bitmapの要素数に等しい。
+    pub fn draw_bitmap(&mut self, px: i32, py: i32, width: u32, bitmap: &[u8]) {
+        let width = if width <= 8 { width as i32 } else { 8 };
+        for (dy, line) in bitmap.iter().enumerate() {
+            for dx in 0..width {
+                if (*line >> (width - 1 - dx)) & 1 != 0 {
+                    self.put(px + dx, py + dy as i32, true);
+                }
+            }
+        }
+    }
+
+    /// 任意サイズのビットマップを、指定の合成方法で描く
+    ///
+    /// bitmapの各行は(width+7)/8バイトで、左端を先頭バイトのMSBとする。
+    /// (Video RAMと同じ並び) 行数はheightまでで、bitmapの要素が足りない分は描かない。
+    /// 座標は負でもよく、範囲外にはみ出した部分は無視する。
+    pub fn blit(&mut self, px: i32, py: i32, width: u32, height: u32, bitmap: &[u8], op: RasterOp) {
+        let stride = (width as usize).div_ceil(8);
+        if stride == 0 {
+            return;
+        }
+        let rows = (bitmap.len() / stride).min(height as usize) as u32;
+        let (x0, x1) = Self::clip(px, width, self.width);
+        let (y0, y1) = Self::clip(py, rows, self.height);
+        for y in y0..y1 {
+            let line = &bitmap[(y - py) as usize * stride..];
+            for x in x0..x1 {
+                let sx = (x - px) as usize;
+                let src = line[sx / 8] & (0x80 >> (sx % 8)) != 0;
+                let dst = self.get(x, y);
+                self.put(x, y, op.apply(dst, src));
+            }
+        }
+    }
+
+    /// 水平線を描く。(x,y)から右へwidthドット
+    pub fn draw_hline(&mut self, x: i32, y: i32, width: u32, on: bool) {
+        if y < 0 || y as usize >= self.height {
+            return;
+        }
+        let (x0, x1) = Self::clip(x, width, self.width);
+        for x in x0..x1 {
+            self.put(x, y, on);
+        }
+    }
+
+    /// 垂直線を描く。(x,y)から下へheightドット
+    pub fn draw_vline(&mut self, x: i32, y: i32, height: u32, on: bool) {
+        if x < 0 || x as usize >= self.width {
+            return;
+        }
+        let (y0, y1) = Self::clip(y, height, self.height);
+        for y in y0..y1 {
+            self.put(x, y, on);
+        }
+    }
+
+    /// (x0,y0)から(x1,y1)へ直線を描く(Bresenham)
+    ///
+    /// 先に表示範囲へ切り詰めるので、範囲外に長く伸びた線でも範囲内の分だけ辿る。
+    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, on: bool) {
+        let (x0, y0, x1, y1) = match self.clip_line(x0, y0, x1, y1) {
+            Some(line) => line,
+            None => return,
+        };
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        let (mut x, mut y) = (x0, y0);
+        loop {
+            self.put(x, y, on);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// 矩形の外枠を描く
+    pub fn draw_rect(&mut self, x: i32, y: i32, width: u32, height: u32, on: bool) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        self.draw_hline(x, y, width, on);
+        self.draw_hline(x, Self::far_edge(y, height), width, on);
+        self.draw_vline(x, y, height, on);
+        self.draw_vline(Self::far_edge(x, width), y, height, on);
+    }
+
+    /// 塗りつぶした矩形を描く
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: u32, height: u32, on: bool) {
+        let (y0, y1) = Self::clip(y, height, self.height);
+        for y in y0..y1 {
+            self.draw_hline(x, y, width, on);
+        }
+    }
+
+    /// 中心(cx,cy)、半径rの円を描く(中点円アルゴリズム)
+    ///
+    /// 各点は、表示範囲にかかる行・列の分だけを直接求める。
+    /// (半径が大きくても、処理量はパネルの大きさで決まる)
+    pub fn draw_circle(&mut self, cx: i32, cy: i32, r: u32, on: bool) {
+        let (cx, cy, r) = (cx as i64, cy as i64, r as i64);
+        if !self.circle_visible(cx, cy, r) {
+            return;
+        }
+        let rows = (0..self.height as i64).map(|y| (y - cy).abs());
+        let cols = (0..self.width as i64).map(|x| (x - cx).abs());
+        for y in rows.chain(cols) {
+            if y > r {
+                continue;
+            }
+            let x = Self::circle_x(r, y);
+            if x < y {
+                continue;
+            }
+            for &(dx, dy) in &[(x, y), (y, x), (-y, x), (-x, y)] {
+                self.put_wide(cx + dx, cy + dy, on);
+                self.put_wide(cx - dx, cy - dy, on);
+            }
+        }
+    }
+
+    /// 中心(cx,cy)、半径rの塗りつぶした円を描く
+    ///
+    /// 各行を、draw_circle()の円周の左端から右端まで塗る。
+    pub fn fill_circle(&mut self, cx: i32, cy: i32, r: u32, on: bool) {
+        let (cx, cy, r) = (cx as i64, cy as i64, r as i64);
+        if !self.circle_visible(cx, cy, r) {
+            return;
+        }
+        for y in 0..self.height as i64 {
+            let dy = (y - cy).abs();
+            if dy > r {
+                continue;
+            }
+            let half = Self::circle_half_width(r, dy);
+            let x0 = (cx - half).max(0);
+            let x1 = (cx + half + 1).min(self.width as i64);
+            for x in x0..x1 {
+                self.put_wide(x, y, on);
+            }
+        }
+    }
+
+    /// 円の外接矩形が、表示範囲にかかるか
+    fn circle_visible(&self, cx: i64, cy: i64, r: i64) -> bool {
+        cx + r >= 0 && cy + r >= 0 && cx - r < self.width as i64 && cy - r < self.height as i64
+    }
+
+    /// 中点円アルゴリズムで、中心から縦にyの行に描く円周の横位置(45°より下側)
+    ///
+    /// (x-1/2)^2 + y^2 < r^2 となる最大のx。無ければ-1
+    fn circle_x(r: i64, y: i64) -> i64 {
+        let n = 4 * (r as i128 * r as i128 - y as i128 * y as i128);
+        if n <= 0 {
+            return if y == 0 { 0 } else { -1 };
+        }
+        ((Self::isqrt(n - 1) + 1) / 2) as i64
+    }
+
+    /// 中心から縦にdyの行の、円周の右端までの横幅
+    fn circle_half_width(r: i64, dy: i64) -> i64 {
+        let x = Self::circle_x(r, dy);
+        let mut half = if x >= dy { x } else { -1 };
+        if dy > 0 {
+            // 円周の横位置がdyとなる点(45°より上側)のうち、最も右のもの
+            let m = 4 * r as i128 * r as i128 - (2 * dy as i128 - 1) * (2 * dy as i128 - 1);
+            if m > 0 {
+                let y = ((Self::isqrt(m - 1) / 2) as i64).min(dy);
+                if Self::circle_x(r, y) == dy {
+                    half = half.max(y);
+                }
+            }
+        }
+        half
+    }
+
+    /// 整数の平方根(切り捨て)。nは4*u32::MAX^2まで
+    fn isqrt(n: i128) -> i128 {
+        let (mut lo, mut hi) = (0i128, 1i128 << 34);
+        while lo < hi {
+            let mid = (lo + hi + 1) / 2;
+            if mid * mid <= n {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        lo
+    }
+
+    /// 範囲外を含む座標に点を描く
+    fn put_wide(&mut self, x: i64, y: i64, on: bool) {
+        if x >= 0 && y >= 0 && x < self.width as i64 && y < self.height as i64 {
+            self.put(x as i32, y as i32, on);
+        }
+    }
+
+    /// (x,y)を含む同じ状態の領域(上下左右で連結)を、onで塗りつぶす
+    ///
+    /// 作業用のマスクを使い、領域が広がらなくなるまで走査を繰り返す。
+    pub fn flood_fill(&mut self, x: i32, y: i32, on: bool) {
+        if self.index(x, y).is_none() {
+            return;
+        }
+        let target = self.get(x, y);
+        if target == on {
+            return;
+        }
+        let mut mask = VideoRam::new(self.width, self.height);
+        mask.put(x, y, true);
+        let (w, h) = (self.width as i32, self.height as i32);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            // 順方向と逆方向に走査し、右下・左上への広がりを一度に拾う
+            for i in (0..w * h).chain((0..w * h).rev()) {
+                let (px, py) = (i % w, i / w);
+                if mask.get(px, py) || self.get(px, py) != target {
+                    continue;
+                }
+                if mask.get(px - 1, py)
+                    || mask.get(px + 1, py)
+                    || mask.get(px, py - 1)
+                    || mask.get(px, py + 1)
+                {
+                    mask.put(px, py, true);
+                    changed = true;
+                }
+            }
+        }
+        for py in 0..h {
+            for px in 0..w {
+                if mask.get(px, py) {
+                    self.put(px, py, on);
+                }
+            }
+        }
+    }
+
+    /// 始点posから長さlenの範囲を、0〜limitに切り詰める
+    fn clip(pos: i32, len: u32, limit: usize) -> (i32, i32) {
+        let start = pos.max(0);
+        let end = (pos as i64 + len as i64).min(limit as i64) as i32;
+        (start, end.max(start))
+    }
+
+    /// 始点posから長さlenの範囲の、終端の座標。i32を超える場合はi32::MAX(範囲外)
+    fn far_edge(pos: i32, len: u32) -> i32 {
+        (pos as i64 + len as i64 - 1).min(i32::MAX as i64) as i32
+    }
+
+    /// 線分を表示範囲に切り詰める(Liang–Barsky)。範囲にかからなければNone
+    ///
+    /// 媒介変数tは分数(分子,分母)で持ち、端点は最も近いドットに丸める。
+    fn clip_line(&self, x0: i32, y0: i32, x1: i32, y1: i32) -> Option<(i32, i32, i32, i32)> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let (x0, y0) = (x0 as i64, y0 as i64);
+        let (dx, dy) = (x1 as i64 - x0, y1 as i64 - y0);
+        let (xmax, ymax) = (self.width as i64 - 1, self.height as i64 - 1);
+        let mut t0: (i128, i128) = (0, 1);
+        let mut t1: (i128, i128) = (1, 1);
+        for &(p, q) in &[(-dx, x0), (dx, xmax - x0), (-dy, y0), (dy, ymax - y0)] {
+            let (p, q) = (p as i128, q as i128);
+            if p == 0 {
+                if q < 0 {
+                    return None;
+                }
+                continue;
+            }
+            // t = q / p (分母を正にそろえる)
+            let r = if p < 0 { (-q, -p) } else { (q, p) };
+            if p < 0 {
+                if r.0 * t0.1 > t0.0 * r.1 {
+                    t0 = r;
+                }
+            } else if r.0 * t1.1 < t1.0 * r.1 {
+                t1 = r;
+            }
+        }
+        if t0.0 * t1.1 > t1.0 * t0.1 {
+            return None;
+        }
+        let at = |base: i64, delta: i64, t: (i128, i128)| {
+            let n = 2 * delta as i128 * t.0 + t.1;
+            (base as i128 + n.div_euclid(2 * t.1)) as i32
+        };
+        Some((
+            at(x0, dx, t0),
+            at(y0, dy, t0),
+            at(x0, dx, t1),
+            at(y0, dy, t1),
+        ))
+    }
+
+    /// 座標からバッファのindexとビットマスクを求める
+    fn index(&self, x: i32, y: i32) -> Option<(usize, u8)> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        let (x, y) = (x as usize, y as usize);
+        Some((y * self.stride() + x / 8, 0x80 >> (x % 8)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 点灯しているドットの一覧
+    fn lit(vram: &VideoRam) -> Vec<(i32, i32)> {
+        let mut dots = Vec::new();
+        for y in 0..vram.height() as i32 {
+            for x in 0..vram.width() as i32 {
+                if vram.get(x, y) {
+                    dots.push((x, y));
+                }
+            }
+        }
+        dots
+    }
+
+    #[test]
+    fn line_inside_panel() {
+        let mut vram = VideoRam::new(8, 8);
+        vram.draw_line(0, 0, 3, 1, true);
+        assert_eq!(lit(&vram), vec![(0, 0), (1, 0), (2, 1), (3, 1)]);
+    }
+
+    #[test]
+    fn line_is_clipped_to_panel() {
+        let mut vram = VideoRam::new(16, 8);
+        vram.draw_line(-100, 3, 100, 3, true);
+        assert_eq!(lit(&vram), (0..16).map(|x| (x, 3)).collect::<Vec<_>>());
+
+        let mut vram = VideoRam::new(8, 8);
+        vram.draw_line(-4, -4, 11, 11, true);
+        assert_eq!(lit(&vram), (0..8).map(|i| (i, i)).collect::<Vec<_>>());
+
+        let mut vram = VideoRam::new(8, 8);
+        vram.draw_line(-10, 20, 20, -10, true);
+        assert_eq!(lit(&vram), vec![(7, 3), (6, 4), (5, 5), (4, 6), (3, 7)]);
+    }
+
+    #[test]
+    fn line_outside_panel() {
+        let mut vram = VideoRam::new(8, 8);
+        vram.draw_line(-5, -1, 20, -1, true);
+        vram.draw_line(9, 0, 20, 7, true);
+        assert!(lit(&vram).is_empty());
+    }
+
+    #[test]
+    fn line_extreme_coordinates() {
+        let mut vram = VideoRam::new(8, 8);
+        vram.draw_line(i32::MIN, 0, i32::MAX, 0, true);
+        vram.draw_line(0, i32::MAX, 0, i32::MIN, true);
+        let dots = lit(&vram);
+        assert_eq!(dots.len(), 8 + 8 - 1);
+        assert!(dots.iter().all(|&(x, y)| x == 0 || y == 0));
+    }
+
+    #[test]
+    fn rect_extreme_size() {
+        let mut vram = VideoRam::new(8, 8);
+        vram.draw_rect(i32::MAX, i32::MAX, u32::MAX, u32::MAX, true);
+        assert!(lit(&vram).is_empty());
+        vram.draw_rect(2, 2, u32::MAX, u32::MAX, true);
+        let dots = lit(&vram);
+        assert!(dots.contains(&(2, 2)) && dots.contains(&(7, 2)) && dots.contains(&(2, 7)));
+        assert_eq!(dots.len(), 6 + 6 - 1);
+    }
+
+    #[test]
+    fn blit_negative_position() {
+        let mut vram = VideoRam::new(8, 8);
+        // 上の1行と左の3ドットは、範囲外
+        vram.blit(-3, -1, 8, 2, &[0xff, 0x81], RasterOp::Copy);
+        assert_eq!(lit(&vram), vec![(4, 0)]);
+        vram.blit(-8, 0, 8, 8, &[0xff; 8], RasterOp::Copy);
+        assert_eq!(lit(&vram), vec![(4, 0)]);
+    }
+
+    #[test]
+    fn blit_straddles_bytes() {
+        let mut vram = VideoRam::new(24, 4);
+        // 幅12ドット(1行2バイト)。2バイト目の下位4bitは使わない
+        vram.blit(5, 1, 12, 4, &[0xab, 0xcf, 0x80, 0x10], RasterOp::Copy);
+        assert_eq!(
+            [vram.byte(0, 1), vram.byte(1, 1), vram.byte(2, 1)],
+            [0x05, 0x5e, 0x00]
+        );
+        assert_eq!(
+            [vram.byte(0, 2), vram.byte(1, 2), vram.byte(2, 2)],
+            [0x04, 0x00, 0x80]
+        );
+        // bitmapの行数が足りない分は描かない
+        assert_eq!(lit(&vram).len(), 7 + 2);
+    }
+
+    #[test]
+    fn blit_raster_ops() {
+        let ops = [
+            (RasterOp::Copy, 0xaa),
+            (RasterOp::Or, 0xee),
+            (RasterOp::And, 0x88),
+            (RasterOp::Xor, 0x66),
+            (RasterOp::AndNot, 0x44),
+        ];
+        for &(op, expected) in &ops {
+            let mut vram = VideoRam::new(8, 1);
+            vram.blit(0, 0, 8, 1, &[0xcc], RasterOp::Copy);
+            vram.clear_dirty();
+            vram.blit(0, 0, 8, 1, &[0xaa], op);
+            assert_eq!(vram.byte(0, 0), expected, "{:?}", op);
+            assert_eq!(vram.is_dirty(0, 0), expected != 0xcc);
+        }
+    }
+
+    #[test]
+    fn circle_small() {
+        let mut vram = VideoRam::new(8, 8);
+        vram.draw_circle(3, 3, 2, true);
+        let rows = |vram: &VideoRam| (0..8).map(|y| vram.byte(0, y)).collect::<Vec<_>>();
+        assert_eq!(
+            rows(&vram),
+            [0x00, 0x38, 0x44, 0x44, 0x44, 0x38, 0x00, 0x00]
+        );
+        vram.fill_circle(3, 3, 2, true);
+        assert_eq!(
+            rows(&vram),
+            [0x00, 0x38, 0x7c, 0x7c, 0x7c, 0x38, 0x00, 0x00]
+        );
+        vram.clear();
+        vram.draw_circle(-4, 3, 4, true);
+        assert_eq!(lit(&vram), vec![(0, 2), (0, 3), (0, 4)]);
+    }
+
+    #[test]
+    fn circle_extreme_size() {
+        let mut vram = VideoRam::new(8, 8);
+        // パネルが円の内側に収まれば、円周は見えない
+        vram.draw_circle(3, 3, u32::MAX, true);
+        assert!(lit(&vram).is_empty());
+        vram.draw_circle(i32::MIN, i32::MIN, u32::MAX / 4, true);
+        assert!(lit(&vram).is_empty());
+        vram.fill_circle(i32::MAX, i32::MAX, 0, true);
+        assert!(lit(&vram).is_empty());
+        // 円周がパネルの左端に接する
+        vram.draw_circle(-1_000_000, 3, 1_000_000, true);
+        assert_eq!(lit(&vram), (0..8).map(|y| (0, y)).collect::<Vec<_>>());
+        vram.fill_circle(i32::MAX, i32::MAX, u32::MAX, true);
+        assert_eq!(lit(&vram).len(), 64);
+        vram.clear();
+        vram.fill_circle(i32::MIN, 0, u32::MAX, true);
+        assert_eq!(lit(&vram).len(), 64);
+    }
+}
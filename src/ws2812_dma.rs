@@ -0,0 +1,515 @@
+//! SPI + DMA による WS2812(NeoPixel)チェーンへの送信路
+//!  SPIのMOSIのみを使い、WS2812の1bitをSPIの3bitで表す。
+//!    SPI 3MHz(48MHz/16) 1bit 333ns
+//!    0: 100 (H 333ns / L 667ns)
+//!    1: 110 (H 667ns / L 333ns)
+//!  1画素(G,R,Bの順に24bit)は、9バイトとなる。
+//!
+//! フレームの末尾には、ラッチ用のリセット期間(LO 300μs以上)として0を付加する。
+//! 1フレームを1回のDMA設定で送信でき、割込みはフレーム終了時の1回のみとなる。
+//!
+//! 使用するSPIとDMAストリーム
+//!   SPI1 MOSI:PA7  → DMA2 Stream3 ch3
+//!   SPI2 MOSI:PB15 → DMA1 Stream4 ch0
+//!   SPI3 MOSI:PC12 → DMA1 Stream5 ch0
+//! MAX7219用のSpiDmaとは、別のSPIを使うこと。
+//!
+//! DMAバッファは2面持ち、一方を転送中に、もう一方へ次のフレームを組み立てる。
+//! 保留中のフレームは、次のbegin()で破棄され、新しいフレームに置き換わる。
+//!
+//! DMAのエラーは割込みで記録し、次のbegin()で一度だけ返す。
+
+use core::cell::RefCell;
+use cortex_m::interrupt::{free, Mutex};
+use stm32f4::stm32f401;
+use stm32f4::stm32f401::interrupt;
+
+use crate::matrix_led::{Error, Result};
+use crate::rgb_matrix::{Rgb, MAX_PIXELS};
+use crate::spi_dma::SpiPort;
+use crate::transport::PixelTransport;
+
+/// 1画素のバイト数(24bit * 3)
+const PIXEL_BYTES: usize = 9;
+/// リセット期間のバイト数 (120*8bit / 3MHz = 320μs)
+const RESET_BYTES: usize = 120;
+
+/// SPIをマスター・送信専用・8bit・3MHzに設定し、DMA要求と共に有効にする
+macro_rules! ws_spi_enable {
+    ($spi:expr) => {{
+        $spi.cr1.modify(|_, w| {
+            w.bidimode().unidirectional().
+            dff().eight_bit().
+            lsbfirst().msbfirst().
+            br().div16(). // 基準クロックは48MHz
+            mstr().master().
+            cpol().idle_low().
+            cpha().first_edge().
+            ssm().enabled().
+            ssi().slave_not_selected()
+        });
+        $spi.cr2.modify(|_, w| w.txdmaen().enabled());
+        $spi.cr1.modify(|_, w| w.spe().enabled());
+    }};
+}
+
+/// SPI + DMAによるWS2812への送信路
+pub struct Ws2812Dma<'a> {
+    device: &'a stm32f401::Peripherals,
+    spi: SpiPort,
+    writing: Option<usize>, // 組み立て中のDMAバッファ番号
+}
+
+impl<'a> Ws2812Dma<'a> {
+    /// SPI1(MOSI:PA7)で初期化する
+    pub fn new(device: &'a stm32f401::Peripherals) -> Ws2812Dma<'a> {
+        Self::with_spi(device, SpiPort::Spi1)
+    }
+
+    /// 指定のSPIで初期化する
+    ///
+    /// # Panics
+    /// 既に他のWs2812Dmaが作られている場合
+    pub fn with_spi(device: &'a stm32f401::Peripherals, spi: SpiPort) -> Ws2812Dma<'a> {
+        let claimed = free(|cs| {
+            let mut state = WS_STATE.borrow(cs).borrow_mut();
+            let claimed = state.claimed;
+            state.claimed = true;
+            claimed
+        });
+        assert!(!claimed);
+
+        let ws = Ws2812Dma {
+            device,
+            spi,
+            writing: None,
+        };
+        ws.gpio_setup();
+        ws.spi_setup();
+        ws.dma_setup();
+        ws
+    }
+
+    /// 使用するSPI
+    pub fn spi(&self) -> SpiPort {
+        self.spi
+    }
+
+    /// SPIのDMAコントローラ
+    fn dma(device: &stm32f401::Peripherals, spi: SpiPort) -> &stm32f401::dma2::RegisterBlock {
+        match spi {
+            SpiPort::Spi1 => &device.DMA2,
+            SpiPort::Spi2 | SpiPort::Spi3 => &device.DMA1,
+        }
+    }
+
+    /// SPIのDMAストリーム番号
+    fn stream(spi: SpiPort) -> usize {
+        match spi {
+            SpiPort::Spi1 => 3,
+            SpiPort::Spi2 => 4,
+            SpiPort::Spi3 => 5,
+        }
+    }
+
+    /// 1フレーム分のDMA送信を開始する
+    fn start_frame(device: &stm32f401::Peripherals, spi: SpiPort, buff: &'static WsBuff) {
+        let st = &Self::dma(device, spi).st[Self::stream(spi)];
+        st.m0ar.write(|w| w.m0a().bits(buff.bytes_ptr()));
+        st.ndtr.write(|w| w.ndt().bits(buff.len() as u16));
+        Self::clear_dma_flags(device, spi);
+        st.cr.modify(|_, w| w.en().enabled());
+    }
+
+    /// DMAストリームのエラーフラグを調べる
+    fn dma_error(device: &stm32f401::Peripherals, spi: SpiPort) -> Option<Error> {
+        let (transfer, fifo) = match spi {
+            SpiPort::Spi1 => {
+                let lisr = device.DMA2.lisr.read();
+                (lisr.teif3().is_error(), lisr.dmeif3().is_error())
+            }
+            SpiPort::Spi2 => {
+                let hisr = device.DMA1.hisr.read();
+                (hisr.teif4().is_error(), hisr.dmeif4().is_error())
+            }
+            SpiPort::Spi3 => {
+                let hisr = device.DMA1.hisr.read();
+                (hisr.teif5().is_error(), hisr.dmeif5().is_error())
+            }
+        };
+        if transfer {
+            Some(Error::TransferError)
+        } else if fifo {
+            Some(Error::FifoError)
+        } else {
+            None
+        }
+    }
+
+    /// フレームの送信終了(またはエラーによる中断)時の割込み処理
+    fn on_frame_end(device: &stm32f401::Peripherals, spi: SpiPort) {
+        let error = Self::dma_error(device, spi);
+        // 最後のバイトは送信中だが、リセット期間の途中なので待つ必要はない
+        Self::dma(device, spi).st[Self::stream(spi)]
+            .cr
+            .modify(|_, w| w.en().disabled());
+        Self::clear_dma_flags(device, spi);
+
+        //保留中のフレームがあれば送信開始
+        let next = free(|cs| {
+            let mut state = WS_STATE.borrow(cs).borrow_mut();
+            if error.is_some() {
+                state.error = error;
+            }
+            state.next_frame()
+        });
+        if let Some(index) = next {
+            Self::start_frame(device, spi, &WS_BUFF[index]);
+        }
+    }
+
+    /// DMAストリームの完了・エラーフラグをクリアする
+    fn clear_dma_flags(device: &stm32f401::Peripherals, spi: SpiPort) {
+        match spi {
+            SpiPort::Spi1 => device.DMA2.lifcr.write(|w| {
+                w.ctcif3().clear();
+                w.chtif3().clear();
+                w.cteif3().clear();
+                w.cdmeif3().clear();
+                w.cfeif3().clear()
+            }),
+            SpiPort::Spi2 => device.DMA1.hifcr.write(|w| {
+                w.ctcif4().clear();
+                w.chtif4().clear();
+                w.cteif4().clear();
+                w.cdmeif4().clear();
+                w.cfeif4().clear()
+            }),
+            SpiPort::Spi3 => device.DMA1.hifcr.write(|w| {
+                w.ctcif5().clear();
+                w.chtif5().clear();
+                w.cteif5().clear();
+                w.cdmeif5().clear();
+                w.cfeif5().clear()
+            }),
+        }
+    }
+
+    /// DMAストリームが停止しているか
+    fn is_dma_inactive(&self) -> Result<()> {
+        let st = &Self::dma(self.device, self.spi).st[Self::stream(self.spi)];
+        if st.cr.read().en().is_enabled() {
+            Err(Error::DmaStreamActive)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// gpioのセットアップ
+    ///   MOSIのみ使用。SPI停止中はLOとなるよう、プルダウンする
+    fn gpio_setup(&self) {
+        let rcc = &self.device.RCC;
+        match self.spi {
+            SpiPort::Spi1 => {
+                rcc.ahb1enr.modify(|_, w| w.gpioaen().enabled());
+                let gpioa = &self.device.GPIOA;
+                gpioa.afrl.modify(|_, w| w.afrl7().af5());
+                gpioa.otyper.modify(|_, w| w.ot7().push_pull());
+                gpioa.pupdr.modify(|_, w| w.pupdr7().pull_down());
+                gpioa.ospeedr.modify(|_, w| w.ospeedr7().high_speed());
+                gpioa.moder.modify(|_, w| w.moder7().alternate());
+            }
+            SpiPort::Spi2 => {
+                rcc.ahb1enr.modify(|_, w| w.gpioben().enabled());
+                let gpiob = &self.device.GPIOB;
+                gpiob.afrh.modify(|_, w| w.afrh15().af5());
+                gpiob.otyper.modify(|_, w| w.ot15().push_pull());
+                gpiob.pupdr.modify(|_, w| w.pupdr15().pull_down());
+                gpiob.ospeedr.modify(|_, w| w.ospeedr15().high_speed());
+                gpiob.moder.modify(|_, w| w.moder15().alternate());
+            }
+            SpiPort::Spi3 => {
+                rcc.ahb1enr.modify(|_, w| w.gpiocen().enabled());
+                let gpioc = &self.device.GPIOC;
+                gpioc.afrh.modify(|_, w| w.afrh12().af6());
+                gpioc.otyper.modify(|_, w| w.ot12().push_pull());
+                gpioc.pupdr.modify(|_, w| w.pupdr12().pull_down());
+                gpioc.ospeedr.modify(|_, w| w.ospeedr12().high_speed());
+                gpioc.moder.modify(|_, w| w.moder12().alternate());
+            }
+        }
+    }
+
+    /// SPIのセットアップ
+    fn spi_setup(&self) {
+        let rcc = &self.device.RCC;
+        match self.spi {
+            SpiPort::Spi1 => {
+                rcc.apb2enr.modify(|_, w| w.spi1en().enabled());
+                ws_spi_enable!(self.device.SPI1);
+            }
+            SpiPort::Spi2 => {
+                rcc.apb1enr.modify(|_, w| w.spi2en().enabled());
+                ws_spi_enable!(self.device.SPI2);
+            }
+            SpiPort::Spi3 => {
+                rcc.apb1enr.modify(|_, w| w.spi3en().enabled());
+                ws_spi_enable!(self.device.SPI3);
+            }
+        }
+    }
+
+    /// SPIのデータレジスタのアドレス
+    fn spi_dr(&self) -> u32 {
+        match self.spi {
+            SpiPort::Spi1 => &self.device.SPI1.dr as *const _ as u32,
+            SpiPort::Spi2 => &self.device.SPI2.dr as *const _ as u32,
+            SpiPort::Spi3 => &self.device.SPI3.dr as *const _ as u32,
+        }
+    }
+
+    /// DMAのセットアップ
+    fn dma_setup(&self) {
+        let (channel, interrupt) = match self.spi {
+            SpiPort::Spi1 => {
+                self.device.RCC.ahb1enr.modify(|_, w| w.dma2en().enabled());
+                (3u8, stm32f401::interrupt::DMA2_STREAM3)
+            }
+            SpiPort::Spi2 => {
+                self.device.RCC.ahb1enr.modify(|_, w| w.dma1en().enabled());
+                (0u8, stm32f401::interrupt::DMA1_STREAM4)
+            }
+            SpiPort::Spi3 => {
+                self.device.RCC.ahb1enr.modify(|_, w| w.dma1en().enabled());
+                (0u8, stm32f401::interrupt::DMA1_STREAM5)
+            }
+        };
+        let st = &Self::dma(self.device, self.spi).st[Self::stream(self.spi)];
+        st.cr.modify(|_, w| {
+            w.chsel().bits(channel);
+            w.mburst().single();
+            w.pburst().single();
+            w.ct().memory0();
+            w.dbm().disabled();
+            w.pl().high();
+            w.pincos().psize();
+            w.msize().bits8();
+            w.psize().bits8();
+            w.minc().incremented();
+            w.pinc().fixed();
+            w.circ().disabled();
+            w.dir().memory_to_peripheral();
+            w.tcie().enabled();
+            w.htie().disabled();
+            w.teie().enabled();
+            w.dmeie().enabled()
+        });
+        st.fcr.modify(|_, w| {
+            w.feie().disabled();
+            w.dmdis().disabled();
+            w.fth().half()
+        });
+        st.par.write(|w| w.pa().bits(self.spi_dr()));
+
+        unsafe {
+            cortex_m::peripheral::NVIC::unmask(interrupt);
+        }
+    }
+
+    /// 組み立て中のDMAバッファ
+    fn writing_buff(&self) -> Result<&'static WsBuff> {
+        let index = self.writing.ok_or(Error::NotInitialised)?;
+        Ok(&WS_BUFF[index])
+    }
+}
+
+impl PixelTransport for Ws2812Dma<'_> {
+    /// 転送中でないDMAバッファを確保する
+    /// 保留中のフレームがあれば、破棄してそのバッファを使う
+    /// 前回までの転送でエラーがあれば、そのエラーを返す
+    fn begin(&mut self) -> Result<()> {
+        let index = free(|cs| {
+            let mut state = WS_STATE.borrow(cs).borrow_mut();
+            if let Some(error) = state.error.take() {
+                return Err(error);
+            }
+            state.pending = None;
+            Ok(match state.active {
+                Some(0) => 1,
+                _ => 0,
+            })
+        })?;
+        WS_BUFF[index].clear_buff();
+        self.writing = Some(index);
+        Ok(())
+    }
+
+    fn write(&mut self, pixels: &[Rgb]) -> Result<()> {
+        let buff = self.writing_buff()?;
+        for pixel in pixels {
+            buff.add_pixel(*pixel)?;
+        }
+        Ok(())
+    }
+
+    /// リセット期間を付加し、転送中でなければ送信を開始し、転送中であれば保留する
+    fn flush(&mut self) -> Result<()> {
+        let index = self.writing.take().ok_or(Error::NotInitialised)?;
+        if WS_BUFF[index].len() == 0 {
+            return Ok(()); // 送るものが無い
+        }
+        WS_BUFF[index].add_reset();
+        let start = free(|cs| {
+            let mut state = WS_STATE.borrow(cs).borrow_mut();
+            if state.active.is_none() {
+                state.active = Some(index);
+                true
+            } else {
+                state.pending = Some(index);
+                false
+            }
+        });
+        if start {
+            if let Err(e) = self.is_dma_inactive() {
+                free(|cs| WS_STATE.borrow(cs).borrow_mut().active = None);
+                return Err(e);
+            }
+            Self::start_frame(self.device, self.spi, &WS_BUFF[index]);
+        }
+        Ok(())
+    }
+
+    fn is_busy(&self) -> bool {
+        free(|cs| WS_STATE.borrow(cs).borrow().active.is_some())
+    }
+
+    fn is_pending(&self) -> bool {
+        free(|cs| WS_STATE.borrow(cs).borrow().pending.is_some())
+    }
+}
+
+/// DMA2 Stream3(SPI1送信) 割込み関数
+///   1フレームの送信完了時、及びエラー発生時に呼ばれる
+#[interrupt]
+fn DMA2_STREAM3() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    Ws2812Dma::on_frame_end(&device, SpiPort::Spi1);
+}
+
+/// DMA1 Stream4(SPI2送信) 割込み関数
+///   1フレームの送信完了時、及びエラー発生時に呼ばれる
+#[interrupt]
+fn DMA1_STREAM4() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    Ws2812Dma::on_frame_end(&device, SpiPort::Spi2);
+}
+
+/// DMA1 Stream5(SPI3送信) 割込み関数
+///   1フレームの送信完了時、及びエラー発生時に呼ばれる
+#[interrupt]
+fn DMA1_STREAM5() {
+    let device;
+    unsafe {
+        device = stm32f401::Peripherals::steal();
+    }
+    Ws2812Dma::on_frame_end(&device, SpiPort::Spi3);
+}
+
+/// DMAバッファの使用状況
+struct WsState {
+    active: Option<usize>,  // 転送中のバッファ番号
+    pending: Option<usize>, // 転送待ちのバッファ番号
+    error: Option<Error>,   // 未通知のDMAエラー
+    claimed: bool,          // Ws2812Dmaで使用中か
+}
+
+impl WsState {
+    /// 転送の終わったフレームを、保留中のフレームに切り替える
+    fn next_frame(&mut self) -> Option<usize> {
+        self.active = self.pending.take();
+        self.active
+    }
+}
+
+static WS_STATE: Mutex<RefCell<WsState>> = Mutex::new(RefCell::new(WsState {
+    active: None,
+    pending: None,
+    error: None,
+    claimed: false,
+}));
+
+/// DMAバッファのバイト数
+const WS_BYTES: usize = MAX_PIXELS * PIXEL_BYTES + RESET_BYTES;
+
+/// DMAバッファ領域(2面)
+/// 　グローバル変数・ws2812_dmaモジュール以外での操作禁止
+///   WS_STATEにて転送中・転送待ちとなっていないバッファのみ操作可能
+static WS_BUFF: [WsBuff; 2] = [WS_BUFF_INIT, WS_BUFF_INIT];
+
+use core::cell::UnsafeCell;
+struct WsBuff {
+    bytes: UnsafeCell<[u8; WS_BYTES]>, // SPIx_DRへ送るバイト
+    byte_count: UnsafeCell<usize>,
+}
+
+const WS_BUFF_INIT: WsBuff = WsBuff {
+    bytes: UnsafeCell::new([0u8; WS_BYTES]),
+    byte_count: UnsafeCell::new(0),
+};
+
+unsafe impl Sync for WsBuff {}
+
+impl WsBuff {
+    pub fn clear_buff(&self) {
+        unsafe {
+            *self.byte_count.get() = 0;
+        }
+    }
+
+    /// 1画素をG,R,Bの順に、1bitを3bitに展開して積む
+    pub fn add_pixel(&self, pixel: Rgb) -> Result<()> {
+        unsafe {
+            let index = *self.byte_count.get();
+            if index + PIXEL_BYTES > WS_BYTES - RESET_BYTES {
+                return Err(Error::BufferOverflow);
+            }
+            let bytes = &mut *self.bytes.get();
+            for (i, c) in [pixel.g, pixel.r, pixel.b].iter().enumerate() {
+                let mut bits: u32 = 0;
+                for b in (0..8).rev() {
+                    bits = (bits << 3) | if c & (1 << b) != 0 { 0b110 } else { 0b100 };
+                }
+                bytes[index + i * 3] = (bits >> 16) as u8;
+                bytes[index + i * 3 + 1] = (bits >> 8) as u8;
+                bytes[index + i * 3 + 2] = bits as u8;
+            }
+            *self.byte_count.get() += PIXEL_BYTES;
+        }
+        Ok(())
+    }
+
+    /// ラッチ用のリセット期間(LO)を積む
+    pub fn add_reset(&self) {
+        unsafe {
+            let index = *self.byte_count.get();
+            for byte in &mut (*self.bytes.get())[index..index + RESET_BYTES] {
+                *byte = 0;
+            }
+            *self.byte_count.get() += RESET_BYTES;
+        }
+    }
+
+    fn len(&self) -> usize {
+        unsafe { *self.byte_count.get() }
+    }
+
+    fn bytes_ptr(&self) -> u32 {
+        self.bytes.get() as u32
+    }
+}